@@ -41,7 +41,7 @@ let package = Package(
         ),
         .testTarget(
             name: "PortKillerTests",
-            dependencies: ["PortKiller"],
+            dependencies: ["PortKiller", "Defaults"],
             path: "Tests"
         )
     ]
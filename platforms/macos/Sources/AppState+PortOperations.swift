@@ -1,7 +1,11 @@
 import Foundation
+import Defaults
 
 extension AppState {
     /// Refreshes the port list by scanning for active ports.
+    ///
+    /// If a scan is already in flight, a single follow-up scan is queued and this returns
+    /// immediately; use `refreshForced()` to wait for that follow-up.
     @discardableResult
     func refresh() async -> Bool {
         if isScanning {
@@ -14,8 +18,19 @@ extension AppState {
         repeat {
             hasPendingRefreshRequest = false
             isScanning = true
-
-            let scanned = await scanner.scanPorts()
+            // Waiters queued during the previous pass are satisfied by this one.
+            let waiters = forcedRefreshWaiters
+            forcedRefreshWaiters.removeAll()
+
+            scanGeneration += 1
+            let generation = scanGeneration
+            var scanned = await scanner.scanPorts()
+            lastScanBackend = await scanner.lastBackend()
+            if Defaults[.resolveHostnames] {
+                scanned = await hostnameResolver.annotate(scanned)
+            }
+            // After the last await, so a kill that lands mid-pass is still filtered out.
+            scanned = discardKilledProcesses(from: scanned, scanGeneration: generation)
             let previousPorts = ports
             let didChange = updatePorts(scanned)
             didChangeAny = didChangeAny || didChange
@@ -33,17 +48,56 @@ extension AppState {
                 Task { await self?.killPort(port) }
             }
 
+            // An empty list from an lsof that never ran isn't fresh data.
+            if lastScanBackend != .unavailable {
+                lastRefreshInstant = ContinuousClock.now
+                lastRefreshTime = Date()
+            }
             isScanning = false
+            for waiter in waiters {
+                waiter.resume()
+            }
         } while hasPendingRefreshRequest
 
         return didChangeAny
     }
 
+    /// Refreshes the port list on explicit user request.
+    ///
+    /// Unlike `refresh()`, this is never coalesced away: if a scan is already in flight,
+    /// a follow-up scan is queued and this returns once that scan has completed.
+    func refreshForced() async {
+        guard isScanning else {
+            await refresh()
+            return
+        }
+
+        hasPendingRefreshRequest = true
+        await withCheckedContinuation { continuation in
+            forcedRefreshWaiters.append(continuation)
+        }
+    }
+
+    /// Drops rows for processes killed after this scan started, so a stale in-flight scan
+    /// can't resurrect them. Kills that predate the scan are forgotten: it saw their outcome.
+    private func discardKilledProcesses(from scanned: [PortInfo], scanGeneration generation: Int) -> [PortInfo] {
+        killedPids = killedPids.filter { $0.value >= generation }
+        guard !killedPids.isEmpty else { return scanned }
+        return scanned.filter { killedPids[$0.pid] == nil }
+    }
+
+    /// Removes every row owned by a killed process right away instead of waiting for the next scan.
+    func removeKilledProcess(pid: Int) {
+        ports.removeAll { $0.pid == pid }
+        killedPids[pid] = scanGeneration
+    }
+
     /// Updates the internal port list only if there are changes.
     @discardableResult
     func updatePorts(_ newPorts: [PortInfo]) -> Bool {
-        let newSet = Set(newPorts.map { "\($0.port)-\($0.pid)" })
-        let oldSet = Set(ports.map { "\($0.port)-\($0.pid)" })
+        // Hostname is part of the key so toggling resolution updates the list.
+        let newSet = Set(newPorts.map { "\($0.port)-\($0.pid)-\($0.hostname ?? "")" })
+        let oldSet = Set(ports.map { "\($0.port)-\($0.pid)-\($0.hostname ?? "")" })
         guard newSet != oldSet else { return false }
 
         ports = newPorts.sorted { a, b in
@@ -55,35 +109,56 @@ extension AppState {
         return true
     }
 
+    /// Returns the scanned ports grouped by owning PID.
+    func portsByPid() async -> [ProcessPorts] {
+        await PortGroupingService.shared.groupByPid(ports)
+    }
+
+    /// Whether `port` keeps coming back: killed repeatedly within the last minute and
+    /// listening again, which usually means a supervisor or launchd agent restarts it.
+    func isRepeatOffender(_ port: Int) -> Bool {
+        killHistory.isRepeatOffender(port: port) && ports.contains { $0.port == port }
+    }
+
     /// Kills the process using the specified port.
     func killPort(_ port: PortInfo) async {
         if await scanner.killProcessGracefully(pid: port.pid) {
-            ports.removeAll { $0.id == port.id }
-            await refresh()
+            killHistory.record(port: port.port)
+            removeKilledProcess(pid: port.pid)
+            await refreshForced()
         }
     }
 
     /// Kills the listening process and all processes with ESTABLISHED connections to the port.
     func killPortDeep(_ port: PortInfo) async {
         // 1. Kill the listener
-        _ = await scanner.killProcessGracefully(pid: port.pid)
+        let killedListener = await scanner.killProcessGracefully(pid: port.pid)
 
         // 2. Find and kill ESTABLISHED connections
         let establishedPids = await scanner.findEstablishedPids(for: port.port)
         for pid in establishedPids where pid != port.pid {
-            _ = await scanner.killProcessGracefully(pid: pid)
+            if await scanner.killProcessGracefully(pid: pid) {
+                removeKilledProcess(pid: pid)
+            }
         }
 
-        ports.removeAll { $0.id == port.id }
-        await refresh()
+        // A failed kill (e.g. a root-owned process) leaves the row for the scan to confirm.
+        if killedListener {
+            killHistory.record(port: port.port)
+            removeKilledProcess(pid: port.pid)
+        }
+        await refreshForced()
     }
 
     /// Kills all processes currently using ports.
     func killAll() async {
-        for port in ports {
-            _ = await scanner.killProcessGracefully(pid: port.pid)
+        for pid in Set(ports.map(\.pid)) {
+            guard await scanner.killProcessGracefully(pid: pid) else { continue }
+            for port in ports where port.pid == pid {
+                killHistory.record(port: port.port)
+            }
+            removeKilledProcess(pid: pid)
         }
-        ports.removeAll()
-        await refresh()
+        await refreshForced()
     }
 }
@@ -13,6 +13,7 @@ extension Defaults.Keys {
     static let hideSystemProcesses = Key<Bool>("hideSystemProcesses", default: false)
     static let skipKillConfirmation = Key<Bool>("skipKillConfirmation", default: false)
     static let refreshInterval = Key<Int>("refreshInterval", default: 5)
+    static let resolveHostnames = Key<Bool>("resolveHostnames", default: false)
     static let cloudflaredProtocol = Key<CloudflaredProtocol>("cloudflaredProtocol", default: .http2)
 
     // Process type overrides (processName → ProcessType.rawValue)
@@ -71,6 +72,26 @@ final class AppState {
     /// Whether a port scan is currently in progress
     var isScanning = false
 
+    /// Backend that produced the last scan; `.unavailable` means the list is empty because lsof couldn't run
+    var lastScanBackend: ScannerBackend?
+
+    /// Recent kills per port, for spotting processes that respawn after being killed
+    var killHistory = KillHistory()
+
+    /// Wall-clock time the last successful scan completed, for display ("updated 3s ago");
+    /// nil until lsof has actually run
+    var lastRefreshTime: Date?
+
+    /// Monotonic time the last successful scan completed; unaffected by clock changes, so ages stay correct
+    var lastRefreshInstant: ContinuousClock.Instant?
+
+    /// Time since the last successful scan, or nil before one finishes.
+    /// Views are notified when a scan lands, not as time passes; a live "3s ago" label
+    /// has to re-read this on a timer (e.g. inside a `TimelineView`).
+    var lastRefreshAge: Duration? {
+        lastRefreshInstant.map { ContinuousClock.now - $0 }
+    }
+
     // MARK: - Filter State
 
     /// Current filter settings for the port list
@@ -236,19 +257,31 @@ final class AppState {
     /// Port scanning actor
     let scanner: PortScannerProtocol
 
+    /// Reverse-resolves scanned addresses when `Defaults[.resolveHostnames]` is on
+    let hostnameResolver: HostnameResolver
+
     /// Background task for auto-refresh
     @ObservationIgnored var refreshTask: Task<Void, Never>?
     /// Coalesces concurrent refresh requests into a single follow-up scan.
     @ObservationIgnored var hasPendingRefreshRequest = false
+    /// Forced refreshes waiting for their follow-up scan to complete.
+    @ObservationIgnored var forcedRefreshWaiters: [CheckedContinuation<Void, Never>] = []
+    /// Incremented each time a scan starts; orders scans relative to kills.
+    @ObservationIgnored var scanGeneration = 0
+    /// Killed PIDs → `scanGeneration` at kill time. Scans that started before the kill
+    /// must not bring these rows back.
+    @ObservationIgnored var killedPids: [Int: Int] = [:]
 
     // MARK: - Initialization
 
     init(
         scanner: PortScannerProtocol = PortScanner(),
+        hostnameResolver: HostnameResolver = .shared,
         favoritesState: FavoritesState? = nil,
         watchedPortsState: WatchedPortsState? = nil
     ) {
         self.scanner = scanner
+        self.hostnameResolver = hostnameResolver
         self.favoritesState = favoritesState ?? FavoritesState()
         self.watchedPortsState = watchedPortsState ?? WatchedPortsState()
 
@@ -24,6 +24,11 @@ final class KubernetesDiscoveryManager: Identifiable {
     var selectedPort: KubernetesService.ServicePort?
     var proxyEnabled = true
 
+    /// The kubectl context namespace/service queries run against (nil if unset or unreadable)
+    var currentContext: String?
+    /// Every context in the kubeconfig; only fetched when the context list is opened
+    var contexts: [String] = []
+
     var namespaceState: KubernetesDiscoveryState = .idle
     var serviceState: KubernetesDiscoveryState = .idle
 
@@ -35,6 +40,15 @@ final class KubernetesDiscoveryManager: Identifiable {
 
     // MARK: - Actions
 
+    func loadCurrentContext() async {
+        // Context info is informational only; a failure here must not block namespace loading
+        currentContext = try? await processManager.fetchCurrentContext()
+    }
+
+    func loadContexts() async {
+        contexts = (try? await processManager.fetchContexts()) ?? []
+    }
+
     func loadNamespaces() async {
         namespaceState = .loading
         namespaces = []
@@ -43,6 +57,8 @@ final class KubernetesDiscoveryManager: Identifiable {
         selectedService = nil
         selectedPort = nil
 
+        await loadCurrentContext()
+
         do {
             let fetchedNamespaces = try await processManager.fetchNamespaces()
             // Merge with custom namespaces
@@ -140,6 +156,8 @@ final class KubernetesDiscoveryManager: Identifiable {
         selectedNamespace = nil
         selectedService = nil
         selectedPort = nil
+        currentContext = nil
+        contexts = []
         namespaceState = .idle
         serviceState = .idle
     }
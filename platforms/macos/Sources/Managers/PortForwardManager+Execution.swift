@@ -4,6 +4,11 @@ import Defaults
 extension PortForwardManager {
     /// Runs the port forward process for a connection.
     func runPortForward(for state: PortForwardConnectionState, config: PortForwardConnectionConfig) async {
+        guard !Task.isCancelled else {
+            await abortCancelledStart(for: state)
+            return
+        }
+
         // Direct exec mode: don't use kubectl port-forward, start exec proxy directly
         if config.useDirectExec, config.proxyPort != nil {
             await runDirectExecProxy(for: state, config: config)
@@ -16,7 +21,8 @@ extension PortForwardManager {
                 namespace: config.namespace,
                 service: config.service,
                 localPort: config.localPort,
-                remotePort: config.remotePort
+                remotePort: config.remotePort,
+                verbosity: config.kubectlVerbosity
             )
 
             try await Task.sleep(for: .seconds(2))
@@ -37,6 +43,10 @@ extension PortForwardManager {
                 state.lastError = "Port forward failed to start"
             }
         } catch {
+            if Task.isCancelled {
+                await abortCancelledStart(for: state)
+                return
+            }
             state.portForwardStatus = .error
             state.lastError = error.localizedDescription
         }
@@ -55,7 +65,8 @@ extension PortForwardManager {
                 namespace: config.namespace,
                 service: config.service,
                 externalPort: proxyPort,
-                remotePort: config.remotePort
+                remotePort: config.remotePort,
+                verbosity: config.kubectlVerbosity
             )
 
             try await Task.sleep(for: .seconds(1))
@@ -69,6 +80,10 @@ extension PortForwardManager {
                 state.lastError = "Direct exec proxy failed to start"
             }
         } catch {
+            if Task.isCancelled {
+                await abortCancelledStart(for: state)
+                return
+            }
             state.proxyStatus = .error
             state.portForwardStatus = .error
             state.lastError = error.localizedDescription
@@ -96,11 +111,35 @@ extension PortForwardManager {
                 state.lastError = "Socat proxy failed to start"
             }
         } catch {
+            if Task.isCancelled {
+                await abortCancelledStart(for: state)
+                return
+            }
             state.proxyStatus = .error
             state.lastError = error.localizedDescription
         }
     }
 
+    /// Cleans up after `stopConnection` cancelled a start mid-stabilization.
+    ///
+    /// The cancelled task may have spawned its process after `stopConnection` already ran
+    /// `killProcesses`, so kill again here. Skipped if a newer start has taken over; a start
+    /// that begins during the kill waits for it (see `pendingCleanup`) and keeps its statuses.
+    func abortCancelledStart(for state: PortForwardConnectionState) async {
+        guard state.portForwardTask == nil, state.proxyTask == nil else { return }
+
+        let cleanup = Task { _ = await processManager.killProcesses(for: state.id) }
+        state.pendingCleanup = cleanup
+        await cleanup.value
+        if state.pendingCleanup == cleanup {
+            state.pendingCleanup = nil
+        }
+
+        guard state.portForwardTask == nil, state.proxyTask == nil else { return }
+        state.portForwardStatus = .disconnected
+        state.proxyStatus = .disconnected
+    }
+
     /// Sends a connect notification if enabled (global + per-connection).
     func sendConnectNotificationIfEnabled(for config: PortForwardConnectionConfig) {
         guard Defaults[.portForwardShowNotifications] else { return }
@@ -20,6 +20,8 @@ final class PortForwardManager {
     var connections: [PortForwardConnectionState] = []
     var isMonitoring = false
     var isKillingProcesses = false
+    /// Result of the most recent Stop All / Kill All Stuck, for a UI confirmation
+    var lastStopSummary: PortForwardStopSummary?
 
     var monitorTask: Task<Void, Never>?
     let processManager: PortForwardProcessManager
@@ -67,11 +69,21 @@ final class PortForwardManager {
 
     // MARK: - Connection CRUD
 
-    func addConnection(_ config: PortForwardConnectionConfig) {
+    /// Adds and persists a connection.
+    /// - Throws: `InvalidPortForwardConfigError` if the config fails validation (nothing is saved)
+    func addConnection(_ config: PortForwardConnectionConfig) throws {
+        try Self.ensureValid(config)
         connections.append(PortForwardConnectionState(config: config))
         saveConnections()
     }
 
+    private static func ensureValid(_ config: PortForwardConnectionConfig) throws {
+        let errors = config.validate()
+        guard errors.isEmpty else {
+            throw InvalidPortForwardConfigError(errors: errors)
+        }
+    }
+
     func removeConnection(_ id: UUID) {
         guard let index = connectionIndex(for: id) else { return }
         stopConnection(id)
@@ -79,8 +91,11 @@ final class PortForwardManager {
         saveConnections()
     }
 
-    func updateConnection(_ config: PortForwardConnectionConfig) {
+    /// Replaces a connection's config and persists it.
+    /// - Throws: `InvalidPortForwardConfigError` if the config fails validation (the last valid config is kept)
+    func updateConnection(_ config: PortForwardConnectionConfig) throws {
         guard let index = connectionIndex(for: config.id) else { return }
+        try Self.ensureValid(config)
         let wasConnected = connections[index].isFullyConnected
         if wasConnected {
             stopConnection(config.id)
@@ -95,22 +110,35 @@ final class PortForwardManager {
     // MARK: - Bulk Operations
 
     func startAll() {
+        lastStopSummary = nil
         for connection in connections where connection.config.isEnabled {
             startConnection(connection.id)
         }
         startMonitoring()
     }
 
-    func stopAll() {
+    @discardableResult
+    func stopAll() async -> PortForwardStopSummary {
         stopMonitoring()
+        var summary = PortForwardStopSummary()
         for connection in connections {
-            stopConnection(connection.id)
+            if connection.isActive {
+                summary.connectionsStopped += 1
+            }
+            // Kill before cancelling: a cancelled start reaps its own process, which would
+            // leave it out of the summary.
+            summary.merge(await processManager.killProcesses(for: connection.id))
+            markStopped(connection)
         }
+        lastStopSummary = summary
+        return summary
     }
 
-    func killStuckProcesses() async {
+    @discardableResult
+    func killStuckProcesses() async -> PortForwardStopSummary {
         isKillingProcesses = true
         stopMonitoring()
+        let activeCount = connections.filter(\.isActive).count
 
         for connection in connections {
             connection.portForwardTask?.cancel()
@@ -121,7 +149,8 @@ final class PortForwardManager {
 
         try? await Task.sleep(for: .milliseconds(200))
 
-        await processManager.killAllPortForwarderProcesses()
+        var summary = await processManager.killAllPortForwarderProcesses()
+        summary.connectionsStopped = activeCount
 
         for connection in connections {
             connection.portForwardStatus = .disconnected
@@ -129,6 +158,8 @@ final class PortForwardManager {
         }
 
         isKillingProcesses = false
+        lastStopSummary = summary
+        return summary
     }
 
     // MARK: - Single Connection Operations
@@ -139,17 +170,31 @@ final class PortForwardManager {
         guard state.portForwardTask == nil, state.proxyTask == nil else { return }
         guard state.portForwardStatus != .connecting, state.proxyStatus != .connecting else { return }
         guard !state.isFullyConnected else { return }
-        let config = state.config
+
+        // A bulk-stop confirmation no longer describes the connections once one restarts
+        lastStopSummary = nil
 
         // Reset intentional stop flag when starting
         state.isIntentionallyStopped = false
 
+        let config: PortForwardConnectionConfig
+        do {
+            config = try state.config.resolveEnv()
+        } catch {
+            state.portForwardStatus = .error
+            state.lastError = error.localizedDescription
+            return
+        }
+
         state.portForwardStatus = .connecting
 
         // Set up handlers and start port forward in a single task to ensure proper ordering
         state.portForwardTask = Task { [weak self, weak state] in
             guard let self = self, let state = state else { return }
 
+            // Don't let an aborted start's cleanup kill this start's process or handlers
+            await state.pendingCleanup?.value
+
             // Set log handler with proper weak capture (including inner Task)
             let logHandler: LogHandler = { [weak state] message, type, isError in
                 guard let state = state else { return }
@@ -184,6 +229,16 @@ final class PortForwardManager {
     func stopConnection(_ id: UUID) {
         guard let state = connection(for: id) else { return }
 
+        markStopped(state)
+
+        Task {
+            // killProcesses also drops the stored log/port-conflict handlers.
+            await processManager.killProcesses(for: id)
+        }
+    }
+
+    /// Cancels a connection's tasks and resets it to disconnected without touching its processes.
+    private func markStopped(_ state: PortForwardConnectionState) {
         // Mark as intentionally stopped to avoid disconnect notification
         state.isIntentionallyStopped = true
 
@@ -197,11 +252,6 @@ final class PortForwardManager {
 
         // Clear logs to free memory when connection is stopped
         state.clearLogs()
-
-        Task {
-            // killProcesses also drops the stored log/port-conflict handlers.
-            await processManager.killProcesses(for: id)
-        }
     }
 
     func restartConnection(_ id: UUID) {
@@ -7,21 +7,21 @@ extension PortForwardProcessManager {
         namespace: String,
         service: String,
         localPort: Int,
-        remotePort: Int
+        remotePort: Int,
+        verbosity: Int = 0
     ) async throws -> Process {
-        guard let kubectlPath = DependencyChecker.shared.kubectlPath else {
+        guard let kubectlPath else {
             throw KubectlError.kubectlNotFound
         }
 
         let process = Process()
         process.executableURL = URL(fileURLWithPath: kubectlPath)
-        process.arguments = [
-            "port-forward",
-            "-n", namespace,
-            "svc/\(service)",
-            "\(localPort):\(remotePort)",
-            "--address=127.0.0.1"
-        ]
+        process.arguments = Self.portForwardArguments(
+            namespace: namespace,
+            service: service,
+            portMapping: "\(localPort):\(remotePort)",
+            verbosity: verbosity
+        )
 
         let pipe = Pipe()
         process.standardOutput = pipe
@@ -33,11 +33,7 @@ extension PortForwardProcessManager {
         }
 
         try process.run()
-
-        if processes[id] == nil {
-            processes[id] = [:]
-        }
-        processes[id]?[.portForward] = process
+        trackProcess(process, for: id, type: .portForward)
 
         startReadingOutput(pipe: pipe, id: id, type: .portForward)
 
@@ -71,11 +67,7 @@ extension PortForwardProcessManager {
         }
 
         try process.run()
-
-        if processes[id] == nil {
-            processes[id] = [:]
-        }
-        processes[id]?[.proxy] = process
+        trackProcess(process, for: id, type: .proxy)
 
         startReadingOutput(pipe: pipe, id: id, type: .proxy)
 
@@ -88,9 +80,10 @@ extension PortForwardProcessManager {
         namespace: String,
         service: String,
         externalPort: Int,
-        remotePort: Int
+        remotePort: Int,
+        verbosity: Int = 0
     ) async throws -> Process {
-        guard let kubectlPath = DependencyChecker.shared.kubectlPath else {
+        guard let kubectlPath else {
             throw KubectlError.kubectlNotFound
         }
 
@@ -103,17 +96,12 @@ extension PortForwardProcessManager {
             socatPath: socatPath,
             namespace: namespace,
             service: service,
-            remotePort: remotePort
+            remotePort: remotePort,
+            verbosity: verbosity
         )
 
         let scriptPath = "/tmp/pf-wrapper-\(id.uuidString).sh"
-        try wrapperScript.write(toFile: scriptPath, atomically: true, encoding: .utf8)
-
-        let chmod = Process()
-        chmod.executableURL = URL(fileURLWithPath: "/bin/chmod")
-        chmod.arguments = ["+x", scriptPath]
-        try chmod.run()
-        chmod.waitUntilExit()
+        try writeExecutableScript(wrapperScript, to: scriptPath)
 
         let process = Process()
         process.executableURL = URL(fileURLWithPath: socatPath)
@@ -132,32 +120,71 @@ extension PortForwardProcessManager {
         }
 
         try process.run()
-
-        if processes[id] == nil {
-            processes[id] = [:]
-        }
-        processes[id]?[.proxy] = process
+        trackProcess(process, for: id, type: .proxy)
 
         startReadingOutput(pipe: pipe, id: id, type: .proxy)
 
         return process
     }
 
+    /// Writes `script` to `path` and marks it executable (0755).
+    ///
+    /// Sets the mode through FileManager rather than launching `/bin/chmod`, so a
+    /// missing or sandboxed `chmod` can't leave socat unable to exec the wrapper.
+    nonisolated func writeExecutableScript(_ script: String, to path: String) throws {
+        try script.write(toFile: path, atomically: true, encoding: .utf8)
+        try FileManager.default.setAttributes([.posixPermissions: 0o755], ofItemAtPath: path)
+    }
+
+    /// Builds the `kubectl port-forward` arguments. `portMapping` is `local:remote`
+    /// (the local side may be a shell variable inside the wrapper script).
+    nonisolated static func portForwardArguments(
+        namespace: String,
+        service: String,
+        portMapping: String,
+        verbosity: Int = 0
+    ) -> [String] {
+        var arguments = [
+            "port-forward",
+            "-n", namespace,
+            "svc/\(service)",
+            portMapping,
+            "--address=127.0.0.1"
+        ]
+        if verbosity > 0 {
+            arguments.append("-v=\(verbosity)")
+        }
+        return arguments
+    }
+
     /// Creates a bash wrapper script for multi-connection proxy.
-    func createWrapperScript(
+    ///
+    /// With `verbosity > 0`, kubectl's stderr is left attached so its diagnostics reach
+    /// socat's stderr and from there the connection log. stdout is always discarded:
+    /// the script's stdout is the proxied TCP stream.
+    nonisolated func createWrapperScript(
         kubectlPath: String,
         socatPath: String,
         namespace: String,
         service: String,
-        remotePort: Int
+        remotePort: Int,
+        verbosity: Int = 0
     ) -> String {
-        """
+        let kubectlArguments = Self.portForwardArguments(
+            namespace: namespace,
+            service: service,
+            portMapping: "$PORT:\(remotePort)",
+            verbosity: verbosity
+        ).joined(separator: " ")
+        let redirect = verbosity > 0 ? ">/dev/null" : ">/dev/null 2>&1"
+
+        return """
         #!/bin/bash
         PORT=$((30000 + ($$ % 30000)))
         while /usr/bin/nc -z 127.0.0.1 $PORT 2>/dev/null; do
             PORT=$((PORT + 1))
         done
-        \(kubectlPath) port-forward -n \(namespace) svc/\(service) $PORT:\(remotePort) --address=127.0.0.1 >/dev/null 2>&1 &
+        \(kubectlPath) \(kubectlArguments) \(redirect) &
         KPID=$!
         trap "kill $KPID 2>/dev/null" EXIT
         for i in 1 2 3 4 5 6 7 8 9 10; do
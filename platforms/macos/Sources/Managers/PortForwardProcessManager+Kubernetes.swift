@@ -1,6 +1,18 @@
 import Foundation
 
 extension PortForwardProcessManager {
+    /// Fetches the kubectl context that namespace/service queries will run against.
+    func fetchCurrentContext() async throws -> String {
+        let output = try await executeKubectl(arguments: ["config", "current-context"])
+        return output.trimmingCharacters(in: .whitespacesAndNewlines)
+    }
+
+    /// Fetches the names of all contexts defined in the kubeconfig.
+    func fetchContexts() async throws -> [String] {
+        let output = try await executeKubectl(arguments: ["config", "get-contexts", "-o", "name"])
+        return KubernetesContext.parseNames(from: output)
+    }
+
     /// Fetches all Kubernetes namespaces.
     func fetchNamespaces() async throws -> [KubernetesNamespace] {
         let output = try await executeKubectl(arguments: ["get", "namespaces", "-o", "json"])
@@ -18,7 +30,8 @@ extension PortForwardProcessManager {
     }
 
     /// Fetches services in a specific namespace.
-    func fetchServices(namespace: String) async throws -> [KubernetesService] {
+    /// - Parameter forwardableOnly: Drop ExternalName and port-less services that `kubectl port-forward` can't use
+    func fetchServices(namespace: String, forwardableOnly: Bool = false) async throws -> [KubernetesService] {
         let output = try await executeKubectl(arguments: ["get", "services", "-n", namespace, "-o", "json"])
 
         do {
@@ -26,7 +39,10 @@ extension PortForwardProcessManager {
                 KubernetesService.ListResponse.self,
                 from: Data(output.utf8)
             )
-            let services = KubernetesService.from(response: response)
+            var services = KubernetesService.from(response: response)
+            if forwardableOnly {
+                services = services.filter(\.hasForwardablePorts)
+            }
             return services.sorted { $0.name < $1.name }
         } catch {
             throw KubectlError.parsingFailed(error.localizedDescription)
@@ -35,7 +51,7 @@ extension PortForwardProcessManager {
 
     /// Executes a kubectl command and returns the output.
     nonisolated func executeKubectl(arguments: [String]) async throws -> String {
-        guard let kubectlPath = DependencyChecker.shared.kubectlPath else {
+        guard let kubectlPath else {
             throw KubectlError.kubectlNotFound
         }
 
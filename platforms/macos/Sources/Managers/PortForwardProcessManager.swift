@@ -12,6 +12,15 @@ actor PortForwardProcessManager {
     var logHandlers: [UUID: LogHandler] = [:]
     var portConflictHandlers: [UUID: PortConflictHandler] = [:]
 
+    /// Resolves the kubectl binary at launch time; injectable so tests can substitute a stand-in
+    private let resolveKubectlPath: @Sendable () -> String?
+
+    nonisolated var kubectlPath: String? { resolveKubectlPath() }
+
+    init(kubectlPath: @escaping @Sendable () -> String? = { DependencyChecker.shared.kubectlPath }) {
+        self.resolveKubectlPath = kubectlPath
+    }
+
     // MARK: - Handler Management
 
     func setLogHandler(for id: UUID, handler: @escaping LogHandler) {
@@ -83,7 +92,16 @@ actor PortForwardProcessManager {
 
     // MARK: - Process Lifecycle
 
-    func killProcesses(for id: UUID) {
+    func trackProcess(_ process: Process, for id: UUID, type: PortForwardProcessType) {
+        if processes[id] == nil {
+            processes[id] = [:]
+        }
+        processes[id]?[type] = process
+    }
+
+    /// Terminates the tracked processes for a connection and reports how many were running.
+    @discardableResult
+    func killProcesses(for id: UUID) -> PortForwardStopSummary {
         if let tasks = outputTasks[id] {
             for (_, task) in tasks {
                 task.cancel()
@@ -91,11 +109,13 @@ actor PortForwardProcessManager {
         }
         outputTasks[id] = nil
 
-        guard let procs = processes[id] else { return }
+        var summary = PortForwardStopSummary()
+        guard let procs = processes[id] else { return summary }
 
-        for (_, process) in procs {
+        for (type, process) in procs {
             if process.isRunning {
                 process.terminate()
+                summary.recordKill(of: type)
             }
         }
         processes[id] = nil
@@ -108,6 +128,8 @@ actor PortForwardProcessManager {
 
         let scriptPath = "/tmp/pf-wrapper-\(id.uuidString).sh"
         try? FileManager.default.removeItem(atPath: scriptPath)
+
+        return summary
     }
 
     func isProcessRunning(for id: UUID, type: PortForwardProcessType) -> Bool {
@@ -118,21 +140,41 @@ actor PortForwardProcessManager {
         PortHealthChecker.isPortOpen(port: port)
     }
 
-    func killAllPortForwarderProcesses() async {
-        let pkillKubectl = Process()
-        pkillKubectl.executableURL = URL(fileURLWithPath: "/usr/bin/pkill")
-        pkillKubectl.arguments = ["-9", "-f", "kubectl.*port-forward"]
-        try? pkillKubectl.run()
-        pkillKubectl.waitUntilExit()
+    /// Used for the orphan sweep; skipped (tracked handles only) when it isn't installed.
+    static let pkillPath = "/usr/bin/pkill"
+
+    /// Kills every tracked kubectl/socat process, then sweeps orphans with `pkill`.
+    ///
+    /// Only tracked handles are counted; `pkill` gives no reliable per-process feedback,
+    /// so orphans it catches aren't reflected in the summary.
+    @discardableResult
+    func killAllPortForwarderProcesses() async -> PortForwardStopSummary {
+        var summary = PortForwardStopSummary()
+        for (_, procs) in processes {
+            for (type, process) in procs where process.isRunning {
+                process.terminate()
+                summary.recordKill(of: type)
+            }
+        }
 
-        let pkillSocat = Process()
-        pkillSocat.executableURL = URL(fileURLWithPath: "/usr/bin/pkill")
-        pkillSocat.arguments = ["-9", "-f", "socat.*TCP-LISTEN"]
-        try? pkillSocat.run()
-        pkillSocat.waitUntilExit()
+        let canSweepOrphans = FileManager.default.isExecutableFile(atPath: Self.pkillPath)
+        if canSweepOrphans {
+            await ProcessExecutor.runDiscardingOutput(Self.pkillPath, arguments: ["-9", "-f", "kubectl.*port-forward"])
+            await ProcessExecutor.runDiscardingOutput(Self.pkillPath, arguments: ["-9", "-f", "socat.*TCP-LISTEN"])
+        }
 
         try? await Task.sleep(for: .milliseconds(500))
 
+        if !canSweepOrphans {
+            // Without pkill, orphans from earlier runs can't be found; at least make sure
+            // our own children didn't ignore SIGTERM.
+            for (_, procs) in processes {
+                for (_, process) in procs where process.isRunning {
+                    kill(process.processIdentifier, SIGKILL)
+                }
+            }
+        }
+
         processes.removeAll()
         for (_, tasks) in outputTasks {
             for (_, task) in tasks { task.cancel() }
@@ -141,5 +183,7 @@ actor PortForwardProcessManager {
         connectionErrors.removeAll()
         logHandlers.removeAll()
         portConflictHandlers.removeAll()
+
+        return summary
     }
 }
@@ -0,0 +1,37 @@
+import Foundation
+
+/// Recent kill timestamps per port, used to spot processes that keep respawning
+/// (supervisors, launchd agents) so the UI can suggest stopping the service instead.
+struct KillHistory: Equatable, Sendable {
+    /// Timestamps kept per port; older kills are dropped first
+    static let capacity = 8
+    /// Kills within `repeatWindow` that make a port a repeat offender
+    static let repeatThreshold = 3
+    static let repeatWindow: TimeInterval = 60
+
+    private var killsByPort: [Int: [Date]] = [:]
+
+    /// Records a kill on `port`, evicting the oldest entry once `capacity` is reached
+    mutating func record(port: Int, at date: Date = Date()) {
+        var kills = killsByPort[port, default: []]
+        kills.append(date)
+        if kills.count > Self.capacity {
+            kills.removeFirst(kills.count - Self.capacity)
+        }
+        killsByPort[port] = kills
+    }
+
+    /// Number of kills on `port` within the last `repeatWindow`
+    func recentKillCount(port: Int, now: Date = Date()) -> Int {
+        killsByPort[port, default: []].count { now.timeIntervalSince($0) <= Self.repeatWindow }
+    }
+
+    /// Whether `port` was killed at least `repeatThreshold` times within `repeatWindow`
+    func isRepeatOffender(port: Int, now: Date = Date()) -> Bool {
+        recentKillCount(port: port, now: now) >= Self.repeatThreshold
+    }
+
+    func lastKill(port: Int) -> Date? {
+        killsByPort[port]?.last
+    }
+}
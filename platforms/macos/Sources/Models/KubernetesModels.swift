@@ -14,6 +14,19 @@ struct KubernetesNamespace: Identifiable, Codable, Sendable, Hashable {
     }
 }
 
+// MARK: - Context
+
+enum KubernetesContext {
+    /// Parses `kubectl config get-contexts -o name` output into context names.
+    /// One name per line; blank lines (including the trailing newline) are dropped.
+    static func parseNames(from output: String) -> [String] {
+        output
+            .split(whereSeparator: \.isNewline)
+            .map { $0.trimmingCharacters(in: .whitespaces) }
+            .filter { !$0.isEmpty }
+    }
+}
+
 // MARK: - Service
 
 struct KubernetesService: Identifiable, Codable, Sendable, Hashable {
@@ -21,10 +34,32 @@ struct KubernetesService: Identifiable, Codable, Sendable, Hashable {
     let namespace: String
     let type: String
     let clusterIP: String?
+    /// DNS name an ExternalName service aliases
+    let externalName: String?
     let ports: [ServicePort]
 
     var id: String { "\(namespace)/\(name)" }
 
+    /// Headless services (`clusterIP: None`) have no virtual IP; kubectl forwards to a backing pod
+    var isHeadless: Bool { clusterIP == "None" }
+
+    /// ExternalName services are DNS aliases with no pods behind them, so they can't be forwarded
+    var isExternalName: Bool { type == "ExternalName" }
+
+    /// Whether `kubectl port-forward svc/<name>` has anything to forward to
+    var hasForwardablePorts: Bool { !isExternalName && !ports.isEmpty }
+
+    /// Why the service can't be forwarded, for display in place of its ports
+    var unforwardableReason: String? {
+        if isExternalName {
+            return "Alias for \(externalName ?? "an external host"); ExternalName services can't be port-forwarded"
+        }
+        if ports.isEmpty {
+            return isHeadless ? "Headless service with no ports defined" : "No ports defined"
+        }
+        return nil
+    }
+
     struct ServicePort: Codable, Sendable, Hashable, Identifiable {
         let name: String?
         let port: Int
@@ -78,6 +113,7 @@ extension KubernetesService {
             struct Spec: Codable {
                 let type: String?
                 let clusterIP: String?
+                let externalName: String?
                 let ports: [Port]?
 
                 struct Port: Codable {
@@ -132,6 +168,7 @@ extension KubernetesService {
                 namespace: item.metadata.namespace,
                 type: item.spec.type ?? "ClusterIP",
                 clusterIP: item.spec.clusterIP,
+                externalName: item.spec.externalName,
                 ports: item.spec.ports?.map { port in
                     ServicePort(
                         name: port.name,
@@ -20,6 +20,8 @@ struct PortForwardConnectionConfig: Identifiable, Codable, Equatable, Hashable,
     /// Notification settings
     var notifyOnConnect: Bool
     var notifyOnDisconnect: Bool
+    /// kubectl log verbosity passed as `-v=<n>` for diagnostics (0 omits the flag)
+    var kubectlVerbosity: Int
 
     init(
         id: UUID = UUID(),
@@ -33,7 +35,8 @@ struct PortForwardConnectionConfig: Identifiable, Codable, Equatable, Hashable,
         autoReconnect: Bool = true,
         useDirectExec: Bool = true,
         notifyOnConnect: Bool = true,
-        notifyOnDisconnect: Bool = true
+        notifyOnDisconnect: Bool = true,
+        kubectlVerbosity: Int = 0
     ) {
         self.id = id
         self.name = name
@@ -47,6 +50,7 @@ struct PortForwardConnectionConfig: Identifiable, Codable, Equatable, Hashable,
         self.useDirectExec = useDirectExec
         self.notifyOnConnect = notifyOnConnect
         self.notifyOnDisconnect = notifyOnDisconnect
+        self.kubectlVerbosity = kubectlVerbosity
     }
 
     // MARK: - Codable Migration
@@ -68,12 +72,114 @@ struct PortForwardConnectionConfig: Identifiable, Codable, Equatable, Hashable,
         // New fields with defaults for migration
         notifyOnConnect = try container.decodeIfPresent(Bool.self, forKey: .notifyOnConnect) ?? true
         notifyOnDisconnect = try container.decodeIfPresent(Bool.self, forKey: .notifyOnDisconnect) ?? true
+        kubectlVerbosity = try container.decodeIfPresent(Int.self, forKey: .kubectlVerbosity) ?? 0
     }
 
     private enum CodingKeys: String, CodingKey {
         case id, name, namespace, service, localPort, remotePort, proxyPort
         case isEnabled, autoReconnect, useDirectExec
         case notifyOnConnect, notifyOnDisconnect
+        case kubectlVerbosity
+    }
+}
+
+// MARK: - Validation
+
+extension PortForwardConnectionConfig {
+    private static let validPortRange = 1...65535
+    /// kubectl accepts `-v` levels 0-9
+    static let kubectlVerbosityRange = 0...9
+
+    /// Checks the config for values that can never produce a working forward.
+    /// - Returns: Every violated rule; empty when the config is valid
+    func validate() -> [PortForwardConfigValidationError] {
+        var errors: [PortForwardConfigValidationError] = []
+
+        if name.trimmingCharacters(in: .whitespaces).isEmpty { errors.append(.emptyName) }
+        if namespace.trimmingCharacters(in: .whitespaces).isEmpty { errors.append(.emptyNamespace) }
+        if service.trimmingCharacters(in: .whitespaces).isEmpty { errors.append(.emptyService) }
+
+        if !Self.validPortRange.contains(localPort) { errors.append(.invalidLocalPort(localPort)) }
+        if !Self.validPortRange.contains(remotePort) { errors.append(.invalidRemotePort(remotePort)) }
+
+        if let proxyPort {
+            if !Self.validPortRange.contains(proxyPort) {
+                errors.append(.invalidProxyPort(proxyPort))
+            } else if proxyPort == localPort {
+                errors.append(.proxyPortMatchesLocalPort(proxyPort))
+            }
+        }
+
+        if !Self.kubectlVerbosityRange.contains(kubectlVerbosity) {
+            errors.append(.invalidKubectlVerbosity(kubectlVerbosity))
+        }
+
+        return errors
+    }
+
+    var isValid: Bool { validate().isEmpty }
+}
+
+// MARK: - Environment Substitution
+
+extension PortForwardConnectionConfig {
+    /// Returns a copy with `${VAR}` references in `namespace` and `service` replaced from
+    /// `environment`, so one shared config can work across machines. `$$` is a literal `$`.
+    ///
+    /// Note that apps launched from Finder only see the launchd environment, not shell exports.
+    /// - Throws: `PortForwardEnvironmentError` for unset variables or malformed references
+    func resolveEnv(
+        _ environment: [String: String] = ProcessInfo.processInfo.environment
+    ) throws -> PortForwardConnectionConfig {
+        var resolved = self
+        resolved.namespace = try Self.substituteEnvironment(in: namespace, field: "namespace", environment: environment)
+        resolved.service = try Self.substituteEnvironment(in: service, field: "service", environment: environment)
+        return resolved
+    }
+
+    private static func substituteEnvironment(
+        in value: String,
+        field: String,
+        environment: [String: String]
+    ) throws -> String {
+        guard value.contains("$") else { return value }
+
+        var result = ""
+        var index = value.startIndex
+        while index < value.endIndex {
+            let character = value[index]
+            let next = value.index(after: index)
+
+            guard character == "$", next < value.endIndex else {
+                result.append(character)
+                index = next
+                continue
+            }
+
+            switch value[next] {
+            case "$":
+                result.append("$")
+                index = value.index(after: next)
+            case "{":
+                let nameStart = value.index(after: next)
+                guard let close = value[nameStart...].firstIndex(of: "}") else {
+                    throw PortForwardEnvironmentError.malformedReference(field: field)
+                }
+                let name = String(value[nameStart..<close])
+                guard !name.isEmpty else {
+                    throw PortForwardEnvironmentError.malformedReference(field: field)
+                }
+                guard let replacement = environment[name] else {
+                    throw PortForwardEnvironmentError.undefinedVariable(name: name, field: field)
+                }
+                result.append(replacement)
+                index = value.index(after: close)
+            default:
+                result.append(character)
+                index = next
+            }
+        }
+        return result
     }
 }
 
@@ -129,6 +235,8 @@ final class PortForwardConnectionState: Identifiable, Hashable {
     var logs: [PortForwardLogEntry] = []
     /// Tracks if the connection was stopped intentionally by the user (vs unexpected disconnect)
     var isIntentionallyStopped: Bool = false
+    /// Process cleanup from an aborted start; a new start waits for it before spawning
+    var pendingCleanup: Task<Void, Never>?
 
     /// Maximum log entries to keep per connection (memory optimization)
     private static let maxLogEntries = 100
@@ -154,6 +262,11 @@ final class PortForwardConnectionState: Identifiable, Hashable {
         return portForwardStatus == .connected
     }
 
+    /// Whether the connection is connecting or up (anything a stop would affect)
+    var isActive: Bool {
+        [portForwardStatus, proxyStatus].contains { $0 == .connecting || $0 == .connected }
+    }
+
     /// The effective port that clients should connect to
     var effectivePort: Int {
         config.proxyPort ?? config.localPort
@@ -29,6 +29,63 @@ enum KubectlError: Error, LocalizedError, Sendable {
     }
 }
 
+/// A single rule violated by a `PortForwardConnectionConfig`
+enum PortForwardConfigValidationError: Error, LocalizedError, Equatable, Sendable {
+    case emptyName
+    case emptyNamespace
+    case emptyService
+    case invalidLocalPort(Int)
+    case invalidRemotePort(Int)
+    case invalidProxyPort(Int)
+    case proxyPortMatchesLocalPort(Int)
+    case invalidKubectlVerbosity(Int)
+
+    var errorDescription: String? {
+        switch self {
+        case .emptyName:
+            return "Name must not be empty."
+        case .emptyNamespace:
+            return "Namespace must not be empty."
+        case .emptyService:
+            return "Service must not be empty."
+        case .invalidLocalPort(let port):
+            return "Local port \(port) is out of range (1-65535)."
+        case .invalidRemotePort(let port):
+            return "Remote port \(port) is out of range (1-65535)."
+        case .invalidProxyPort(let port):
+            return "Proxy port \(port) is out of range (1-65535)."
+        case .proxyPortMatchesLocalPort(let port):
+            return "Proxy port and local port must differ (both are \(port))."
+        case .invalidKubectlVerbosity(let level):
+            return "kubectl verbosity \(level) is out of range (0-9)."
+        }
+    }
+}
+
+/// Thrown when saving a config that fails validation; lists every violated rule
+struct InvalidPortForwardConfigError: Error, LocalizedError, Sendable {
+    let errors: [PortForwardConfigValidationError]
+
+    var errorDescription: String? {
+        errors.compactMap(\.errorDescription).joined(separator: "\n")
+    }
+}
+
+/// A `${VAR}` reference in a config field that can't be resolved at start time
+enum PortForwardEnvironmentError: Error, LocalizedError, Equatable, Sendable {
+    case undefinedVariable(name: String, field: String)
+    case malformedReference(field: String)
+
+    var errorDescription: String? {
+        switch self {
+        case .undefinedVariable(let name, let field):
+            return "Environment variable \(name) used in \(field) is not set."
+        case .malformedReference(let field):
+            return "\(field.capitalized) has an invalid ${...} reference. Use $$ for a literal $."
+        }
+    }
+}
+
 // MARK: - Callback Types
 
 /// Callback for log output from port-forward processes
@@ -0,0 +1,27 @@
+import Foundation
+
+/// What a bulk stop actually stopped, counted from our own tracked process handles
+struct PortForwardStopSummary: Equatable, Sendable {
+    var connectionsStopped = 0
+    var kubectlKilled = 0
+    var socatKilled = 0
+
+    /// Records a terminated process of the given type
+    mutating func recordKill(of type: PortForwardProcessType) {
+        switch type {
+        case .portForward: kubectlKilled += 1
+        case .proxy: socatKilled += 1
+        }
+    }
+
+    mutating func merge(_ other: PortForwardStopSummary) {
+        connectionsStopped += other.connectionsStopped
+        kubectlKilled += other.kubectlKilled
+        socatKilled += other.socatKilled
+    }
+
+    /// Short confirmation for the UI (e.g. "Stopped 4 connections")
+    var message: String {
+        connectionsStopped == 1 ? "Stopped 1 connection" : "Stopped \(connectionsStopped) connections"
+    }
+}
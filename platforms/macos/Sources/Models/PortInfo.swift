@@ -46,9 +46,33 @@ struct PortInfo: Identifiable, Hashable, Sendable {
     /// Detected process type (cached at construction time for performance)
     let processType: ProcessType
 
+    /// Reverse-resolved hostname of the bound address (only when hostname resolution is enabled)
+    var hostname: String? = nil
+
     /// Formatted port number for display (e.g., ":3000")
     var displayPort: String { ":\(port)" }
 
+    /// Upper bound (exclusive) of the traditional privileged port range. Since Mojave, macOS
+    /// lets non-root processes bind these on the wildcard address, but specific addresses
+    /// still need root.
+    static let privilegedPortLimit = 1024
+
+    /// Whether the port is in the privileged range (port < 1024)
+    var isPrivilegedPort: Bool { port < Self.privilegedPortLimit }
+
+    /// Warns that reusing a privileged port may need elevation, or nil for unprivileged ports
+    var privilegedPortHint: String? {
+        guard isPrivilegedPort else { return nil }
+        return "Port \(port) is in the privileged range. Rebinding it after killing this process may require administrator privileges."
+    }
+
+    /// Returns a copy with the resolved hostname attached
+    func withHostname(_ hostname: String?) -> PortInfo {
+        var copy = self
+        copy.hostname = hostname
+        return copy
+    }
+
     /// Create an inactive placeholder for a favorited/watched port
     ///
     /// - Parameter port: The port number
@@ -0,0 +1,30 @@
+/**
+ * ProcessPorts.swift
+ * PortKiller
+ *
+ * The set of ports held by a single process (PID).
+ * Process-centric transpose of the port-centric PortInfo list.
+ */
+
+import Foundation
+
+/// All listening ports held by one process
+///
+/// Unlike ProcessGroup (which merges every PID sharing a process name),
+/// ProcessPorts is keyed by PID, so two `node` servers stay separate while
+/// a debugger holding 3000, 3001 and 9229 shows up as one entry.
+struct ProcessPorts: Identifiable, Hashable, Sendable {
+    /// Process ID
+    let pid: Int
+
+    /// Name of the process
+    let processName: String
+
+    /// Full command line of the process
+    let command: String
+
+    /// Port numbers held by this process, ascending
+    let ports: [Int]
+
+    var id: Int { pid }
+}
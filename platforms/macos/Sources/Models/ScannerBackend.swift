@@ -0,0 +1,10 @@
+import Foundation
+
+/// Which path produced the last port scan, so "it shows no ports" reports can tell
+/// an empty system apart from a scanner that never ran.
+enum ScannerBackend: String, Equatable, Sendable {
+    /// `lsof` ran; an empty result means nothing is listening
+    case lsof
+    /// `lsof` couldn't be launched (missing or blocked), so the scan is empty for that reason
+    case unavailable
+}
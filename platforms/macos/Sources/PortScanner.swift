@@ -17,6 +17,20 @@ import Darwin
  * This is an actor, so all methods are isolated and can be called safely from any context.
  */
 actor PortScanner: PortScannerProtocol {
+    /// Runs an executable and returns its trimmed stdout, or nil if it couldn't be launched.
+    typealias CommandRunner = @Sendable (String, [String]) async -> String?
+
+    private let runCommand: CommandRunner
+    private var backend: ScannerBackend?
+
+    init(runCommand: @escaping CommandRunner = { await ProcessExecutor.output($0, arguments: $1) }) {
+        self.runCommand = runCommand
+    }
+
+    /// The backend used by the most recent scan, or nil before the first scan.
+    func lastBackend() -> ScannerBackend? {
+        backend
+    }
 
     /**
      * Scans all listening TCP ports using lsof.
@@ -37,10 +51,14 @@ actor PortScanner: PortScannerProtocol {
         // objects (Process, Pipe, FileHandle, URL, Data) immediately after each scan.
         // Without this, these objects accumulate across the long-lived scanning Task,
         // causing ~35KB per scan × 47,520 scans over 66 hours = ~1.7GB leak.
-        let output = await ProcessExecutor.output(
+        guard let output = await runCommand(
             "/usr/sbin/lsof",
-            arguments: ["-iTCP", "-sTCP:LISTEN", "-P", "-n", "+c", "0"]
-        ) ?? ""
+            ["-iTCP", "-sTCP:LISTEN", "-P", "-n", "+c", "0"]
+        ) else {
+            backend = .unavailable
+            return []
+        }
+        backend = .lsof
 
         guard !output.isEmpty else { return [] }
 
@@ -354,8 +372,10 @@ actor PortScanner: PortScannerProtocol {
             try? await Task.sleep(for: .milliseconds(500))
         }
 
-        // Force kill with SIGKILL (immediate termination)
-        return await killProcess(pid: pid, force: true)
+        // Force kill with SIGKILL (immediate termination). This fails with ESRCH when
+        // SIGTERM already worked, so either signal landing counts as success.
+        let forced = await killProcess(pid: pid, force: true)
+        return graceful || forced
     }
 
     /**
@@ -30,4 +30,26 @@ protocol PortScannerProtocol: Sendable {
     /// - Parameter port: Port number to check
     /// - Returns: Set of PIDs with established connections (excludes the listener)
     func findEstablishedPids(for port: Int) async -> Set<Int>
+
+    /// Reports which backend produced the most recent scan
+    /// - Returns: The last scan's backend, or nil before the first scan
+    func lastBackend() async -> ScannerBackend?
+}
+
+extension PortScannerProtocol {
+    /// Scans and applies `filter` in one call, for callers that don't keep an AppState
+    /// - Parameters:
+    ///   - filter: Filter to apply to the scan result
+    ///   - favorites: Ports treated as favorites when `filter.showOnlyFavorites` is set
+    ///   - watched: Ports treated as watched when `filter.showOnlyWatched` is set
+    /// - Returns: Scanned ports matching the filter, in scan order
+    func scanFiltered(
+        _ filter: PortFilter,
+        favorites: Set<Int> = [],
+        watched: [WatchedPort] = []
+    ) async -> [PortInfo] {
+        let ports = await scanPorts()
+        guard filter.isActive else { return ports }
+        return ports.filter { filter.matches($0, favorites: favorites, watched: watched) }
+    }
 }
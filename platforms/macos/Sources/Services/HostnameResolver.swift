@@ -0,0 +1,162 @@
+import Foundation
+import Darwin
+import Synchronization
+
+/// Reverse-resolves the addresses ports are bound to, for display only.
+///
+/// The scanner runs lsof with `-n` on purpose: name lookups are slow and can hang on a
+/// misconfigured resolver. This is the opt-in (`Defaults[.resolveHostnames]`) escape hatch,
+/// so every wait is bounded by `timeout`. Answers, including "no PTR record", are cached for
+/// the lifetime of the app; a timed-out lookup is not, and fills the cache if it finishes later.
+actor HostnameResolver {
+    typealias Lookup = @Sendable (String) -> String?
+
+    static let shared = HostnameResolver()
+
+    /// getnameinfo blocks for up to the system DNS timeout, so it runs on GCD threads
+    /// rather than Swift's fixed-width cooperative pool, which it could otherwise starve.
+    private static let lookupQueue = DispatchQueue(
+        label: "com.portkiller.hostname-lookup",
+        qos: .utility,
+        attributes: .concurrent
+    )
+
+    private let timeout: Duration
+    private let lookup: Lookup
+    private var cache: [String: String?] = [:]
+    /// Addresses whose lookup is still running (possibly past its timeout)
+    private var pending: Set<String> = []
+
+    init(timeout: Duration = .milliseconds(500), lookup: @escaping Lookup = HostnameResolver.systemLookup) {
+        self.timeout = timeout
+        self.lookup = lookup
+    }
+
+    /// Returns `ports` with `hostname` filled in for non-wildcard, non-loopback addresses.
+    /// Lookups run concurrently, so the whole call takes at most about one `timeout`.
+    func annotate(_ ports: [PortInfo]) async -> [PortInfo] {
+        let addresses = Set(ports.map(\.address).filter(Self.isResolvable))
+        guard !addresses.isEmpty else { return ports }
+
+        let hostnames = await withTaskGroup(of: (String, String?).self) { group in
+            for address in addresses {
+                group.addTask { (address, await self.resolve(address)) }
+            }
+            var resolved: [String: String] = [:]
+            for await (address, hostname) in group {
+                resolved[address] = hostname
+            }
+            return resolved
+        }
+
+        return ports.map { port in
+            guard let hostname = hostnames[port.address] else { return port }
+            return port.withHostname(hostname)
+        }
+    }
+
+    /// Resolves a single address, giving up after `timeout`. A lookup that's still running
+    /// from an earlier call is not restarted; the address stays unresolved until it finishes.
+    func resolve(_ address: String) async -> String? {
+        if let cached = cache[address] {
+            return cached
+        }
+        guard !pending.contains(address) else { return nil }
+        pending.insert(address)
+
+        let host = Self.bareHost(address)
+        let lookup = self.lookup
+        let timeout = self.timeout
+
+        let outcome: LookupOutcome = await withCheckedContinuation { continuation in
+            let result = FirstResult(continuation)
+            Self.lookupQueue.async {
+                let hostname = lookup(host)
+                result.resume(with: .answered(hostname))
+                Task { await self.finishLookup(address, hostname: hostname) }
+            }
+            Task.detached {
+                try? await Task.sleep(for: timeout)
+                result.resume(with: .timedOut)
+            }
+        }
+
+        switch outcome {
+        case .answered(let hostname):
+            finishLookup(address, hostname: hostname)
+            return hostname
+        case .timedOut:
+            return nil
+        }
+    }
+
+    /// Caches a completed lookup, whether it beat the timeout or not.
+    private func finishLookup(_ address: String, hostname: String?) {
+        pending.remove(address)
+        cache[address] = .some(hostname)
+    }
+
+    /// Whether an address is worth resolving (not a wildcard, loopback or placeholder).
+    nonisolated static func isResolvable(_ address: String) -> Bool {
+        let host = bareHost(address)
+        guard !host.isEmpty, host != "*", host != "-" else { return false }
+        if host == "0.0.0.0" || host == "::" { return false }
+        if host == "localhost" || host == "::1" || host.hasPrefix("127.") { return false }
+        return true
+    }
+
+    /// Strips IPv6 brackets: "[fe80::1]" → "fe80::1".
+    nonisolated static func bareHost(_ address: String) -> String {
+        if address.hasPrefix("["), address.hasSuffix("]") {
+            return String(address.dropFirst().dropLast())
+        }
+        return address
+    }
+
+    /// Reverse lookup via getnameinfo. Returns nil when no PTR record exists.
+    nonisolated static func systemLookup(_ host: String) -> String? {
+        var hints = addrinfo()
+        hints.ai_flags = AI_NUMERICHOST
+        var info: UnsafeMutablePointer<addrinfo>?
+        guard getaddrinfo(host, nil, &hints, &info) == 0, let info else { return nil }
+        defer { freeaddrinfo(info) }
+
+        var buffer = [CChar](repeating: 0, count: Int(NI_MAXHOST))
+        let status = getnameinfo(
+            info.pointee.ai_addr,
+            info.pointee.ai_addrlen,
+            &buffer,
+            socklen_t(buffer.count),
+            nil,
+            0,
+            NI_NAMEREQD
+        )
+        guard status == 0 else { return nil }
+
+        let bytes = buffer.prefix { $0 != 0 }.map { UInt8(bitPattern: $0) }
+        return String(decoding: bytes, as: UTF8.self)
+    }
+}
+
+private enum LookupOutcome: Sendable {
+    case answered(String?)
+    case timedOut
+}
+
+/// Resumes a continuation with whichever result arrives first.
+private final class FirstResult: Sendable {
+    private let continuation: Mutex<CheckedContinuation<LookupOutcome, Never>?>
+
+    init(_ continuation: CheckedContinuation<LookupOutcome, Never>) {
+        self.continuation = Mutex(continuation)
+    }
+
+    func resume(with outcome: LookupOutcome) {
+        let pending = continuation.withLock { value -> CheckedContinuation<LookupOutcome, Never>? in
+            let taken = value
+            value = nil
+            return taken
+        }
+        pending?.resume(returning: outcome)
+    }
+}
@@ -83,6 +83,35 @@ actor PortGroupingService {
         }
     }
 
+    /// Groups ports by PID
+    ///
+    /// Each ProcessPorts lists every port a single process is listening on.
+    /// Inactive placeholder ports (favorites/watched that aren't running) are skipped.
+    ///
+    /// - Parameter ports: Array of ports to group
+    /// - Returns: Array of ProcessPorts, sorted by process name then PID
+    ///
+    /// # Example
+    /// ```swift
+    /// let processes = await PortGroupingService.shared.groupByPid(ports)
+    /// // processes[0] might be: ProcessPorts(pid: 1234, processName: "node", ports: [3000, 3001, 9229])
+    /// ```
+    func groupByPid(_ ports: [PortInfo]) -> [ProcessPorts] {
+        let grouped = Dictionary(grouping: ports.filter(\.isActive)) { $0.pid }
+        return grouped.compactMap { pid, ports -> ProcessPorts? in
+            guard let first = ports.first else { return nil }
+            return ProcessPorts(
+                pid: pid,
+                processName: first.processName,
+                command: first.command,
+                ports: Array(Set(ports.map(\.port))).sorted()
+            )
+        }.sorted { a, b in
+            let order = a.processName.localizedCaseInsensitiveCompare(b.processName)
+            return order == .orderedSame ? a.pid < b.pid : order == .orderedAscending
+        }
+    }
+
     /// Groups ports by their process type
     ///
     /// Organizes ports into categories based on their detected process type
@@ -84,6 +84,12 @@ struct PortRowView: View {
                 if port.isActive {
                     TunnelExposureBadge(port: port.port)
                 }
+                if let hint = port.privilegedPortHint {
+                    Image(systemName: "lock.fill")
+                        .font(.caption2)
+                        .foregroundStyle(.secondary)
+                        .help(hint)
+                }
             }
             .frame(width: 90, alignment: .leading)
 
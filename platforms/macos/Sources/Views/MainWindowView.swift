@@ -156,7 +156,7 @@ struct MainWindowView: View {
         ToolbarItemGroup(placement: .primaryAction) {
             Button {
                 Task {
-                    await appState.refresh()
+                    await appState.refreshForced()
                 }
             } label: {
                 Label("Refresh", systemImage: "arrow.clockwise")
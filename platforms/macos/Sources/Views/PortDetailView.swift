@@ -117,6 +117,34 @@ struct PortDetailView: View {
                     .clipShape(Capsule())
                 }
 
+                if let hint = port.privilegedPortHint {
+                    HStack(spacing: 4) {
+                        Image(systemName: "lock.fill")
+                        Text("Privileged")
+                    }
+                    .font(.caption)
+                    .padding(.horizontal, 8)
+                    .padding(.vertical, 4)
+                    .background(.gray.opacity(0.2))
+                    .foregroundStyle(.secondary)
+                    .clipShape(Capsule())
+                    .help(hint)
+                }
+
+                if appState.isRepeatOffender(port.port) {
+                    HStack(spacing: 4) {
+                        Image(systemName: "arrow.clockwise")
+                        Text("Respawning")
+                    }
+                    .font(.caption)
+                    .padding(.horizontal, 8)
+                    .padding(.vertical, 4)
+                    .background(.orange.opacity(0.2))
+                    .foregroundStyle(.orange)
+                    .clipShape(Capsule())
+                    .help("This process keeps restarting after being killed. Disable the launchd service or supervisor that runs it instead.")
+                }
+
                 if appState.isWatching(port.port) {
                     HStack(spacing: 4) {
                         Image(systemName: "eye.fill")
@@ -191,6 +219,9 @@ struct PortDetailView: View {
             DetailRow(title: "Label", value: appState.portLabel(for: port.port) ?? "—")
             DetailRow(title: "PID", value: String(port.pid))
             DetailRow(title: "Address", value: port.address)
+            if let hostname = port.hostname {
+                DetailRow(title: "Hostname", value: hostname)
+            }
             DetailRow(title: "User", value: port.user)
             DetailRow(title: "File Descriptor", value: port.fd)
             DetailRow(title: "Type", value: port.processType.rawValue)
@@ -0,0 +1,72 @@
+import SwiftUI
+
+/// Shows the kubectl context the browser is querying; click to list every context in the kubeconfig.
+struct KubernetesContextBadge: View {
+    let currentContext: String
+    let contexts: [String]
+    let onLoadContexts: () async -> Void
+
+    @State private var showingContexts = false
+    @State private var isLoadingContexts = false
+
+    var body: some View {
+        Button {
+            showingContexts = true
+        } label: {
+            HStack(spacing: 4) {
+                Image(systemName: "server.rack")
+                Text(currentContext)
+                    .lineLimit(1)
+                    .truncationMode(.middle)
+                Spacer()
+            }
+            .font(.caption)
+            .foregroundStyle(.secondary)
+            .contentShape(Rectangle())
+        }
+        .buttonStyle(.plain)
+        .help("kubectl context: \(currentContext)")
+        .popover(isPresented: $showingContexts, arrowEdge: .trailing) {
+            contextList
+                .task {
+                    isLoadingContexts = true
+                    await onLoadContexts()
+                    isLoadingContexts = false
+                }
+        }
+    }
+
+    private var contextList: some View {
+        VStack(alignment: .leading, spacing: 6) {
+            Text("Contexts")
+                .font(.caption)
+                .foregroundStyle(.secondary)
+
+            if isLoadingContexts && contexts.isEmpty {
+                ProgressView()
+                    .controlSize(.small)
+            } else if contexts.isEmpty {
+                Text("No contexts found")
+                    .font(.caption)
+                    .foregroundStyle(.tertiary)
+            } else {
+                ForEach(contexts, id: \.self) { context in
+                    HStack(spacing: 6) {
+                        Image(systemName: "checkmark")
+                            .opacity(context == currentContext ? 1 : 0)
+                        Text(context)
+                            .font(.system(.caption, design: .monospaced))
+                    }
+                }
+            }
+
+            Divider()
+
+            Text("Switch with `kubectl config use-context <name>`")
+                .font(.caption2)
+                .foregroundStyle(.tertiary)
+        }
+        .padding(10)
+        .frame(minWidth: 200, alignment: .leading)
+    }
+}
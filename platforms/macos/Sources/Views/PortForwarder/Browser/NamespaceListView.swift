@@ -4,6 +4,9 @@ struct NamespaceListView: View {
     let namespaces: [KubernetesNamespace]
     let selectedNamespace: KubernetesNamespace?
     let state: KubernetesDiscoveryState
+    let currentContext: String?
+    let contexts: [String]
+    let onLoadContexts: () async -> Void
     let onSelect: (KubernetesNamespace) -> Void
     let onRefresh: () -> Void
     let onAddCustom: ([String]) -> Void
@@ -39,6 +42,16 @@ struct NamespaceListView: View {
             .padding(.horizontal, 10)
             .padding(.vertical, 8)
 
+            if let currentContext {
+                KubernetesContextBadge(
+                    currentContext: currentContext,
+                    contexts: contexts,
+                    onLoadContexts: onLoadContexts
+                )
+                .padding(.horizontal, 10)
+                .padding(.bottom, 6)
+            }
+
             Divider()
 
             Group {
@@ -4,6 +4,9 @@ struct NamespacePanel: View {
     let namespaces: [KubernetesNamespace]
     let selectedNamespace: KubernetesNamespace?
     let state: KubernetesDiscoveryState
+    let currentContext: String?
+    let contexts: [String]
+    let onLoadContexts: () async -> Void
     let onSelect: (KubernetesNamespace) -> Void
     let onRefresh: () -> Void
     let onAddCustom: ([String]) -> Void
@@ -35,6 +38,16 @@ struct NamespacePanel: View {
             }
             .padding(12)
 
+            if let currentContext {
+                KubernetesContextBadge(
+                    currentContext: currentContext,
+                    contexts: contexts,
+                    onLoadContexts: onLoadContexts
+                )
+                .padding(.horizontal, 12)
+                .padding(.bottom, 8)
+            }
+
             Divider()
 
             if state == .loading {
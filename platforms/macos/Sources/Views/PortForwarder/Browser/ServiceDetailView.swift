@@ -37,7 +37,11 @@ struct ServiceDetailView: View {
                                 .padding(.vertical, 2)
                                 .background(Color.secondary.opacity(0.2))
                                 .clipShape(RoundedRectangle(cornerRadius: 4))
-                            if let ip = service.clusterIP, ip != "None" {
+                            if service.isHeadless {
+                                Text("Headless")
+                                    .font(.caption)
+                                    .foregroundStyle(.secondary)
+                            } else if let ip = service.clusterIP {
                                 Text(ip)
                                     .font(.caption)
                                     .foregroundStyle(.secondary)
@@ -52,8 +56,8 @@ struct ServiceDetailView: View {
                         .font(.caption)
                         .foregroundStyle(.secondary)
 
-                    if service.ports.isEmpty {
-                        Text("No ports defined")
+                    if let reason = service.unforwardableReason {
+                        Text(reason)
                             .font(.caption)
                             .foregroundStyle(.tertiary)
                     } else {
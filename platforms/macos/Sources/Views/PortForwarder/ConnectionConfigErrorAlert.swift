@@ -0,0 +1,18 @@
+import SwiftUI
+
+extension View {
+    /// Shows why a port-forward connection couldn't be saved; dismissing clears the message.
+    func connectionConfigErrorAlert(_ message: Binding<String?>) -> some View {
+        alert(
+            "Can't Save Connection",
+            isPresented: Binding(
+                get: { message.wrappedValue != nil },
+                set: { if !$0 { message.wrappedValue = nil } }
+            )
+        ) {
+            Button("OK", role: .cancel) {}
+        } message: {
+            Text(message.wrappedValue ?? "")
+        }
+    }
+}
@@ -18,6 +18,9 @@ struct ConnectionEditSection: View {
     @State private var useDirectExec: Bool = true
     @State private var notifyOnConnect: Bool = true
     @State private var notifyOnDisconnect: Bool = true
+    @State private var kubectlVerbosity: Int = 0
+    /// Why the current edits weren't saved (the last valid config stays in effect)
+    @State private var saveError: String?
 
     // Kubernetes discovery
     @State private var namespaces: [KubernetesNamespace] = []
@@ -33,6 +36,13 @@ struct ConnectionEditSection: View {
 
             if isExpanded {
                 VStack(spacing: 16) {
+                    if let saveError {
+                        Label(saveError, systemImage: "exclamationmark.triangle.fill")
+                            .font(.caption)
+                            .foregroundStyle(.red)
+                            .frame(maxWidth: .infinity, alignment: .leading)
+                    }
+
                     ConnectionInfoSection(
                         name: $name,
                         namespace: $namespace,
@@ -65,7 +75,8 @@ struct ConnectionEditSection: View {
                         autoReconnect: $autoReconnect,
                         isEnabled: $isEnabled,
                         notifyOnConnect: $notifyOnConnect,
-                        notifyOnDisconnect: $notifyOnDisconnect
+                        notifyOnDisconnect: $notifyOnDisconnect,
+                        kubectlVerbosity: $kubectlVerbosity
                     )
                 }
                 .padding(16)
@@ -98,6 +109,7 @@ struct ConnectionEditSection: View {
         .onChange(of: useDirectExec) { saveToConnection() }
         .onChange(of: notifyOnConnect) { saveToConnection() }
         .onChange(of: notifyOnDisconnect) { saveToConnection() }
+        .onChange(of: kubectlVerbosity) { saveToConnection() }
     }
 
     // MARK: - Header
@@ -180,7 +192,7 @@ struct ConnectionEditSection: View {
         services = []
         Task {
             do {
-                let result = try await appState.portForwardManager.processManager.fetchServices(namespace: ns)
+                let result = try await appState.portForwardManager.processManager.fetchServices(namespace: ns, forwardableOnly: true)
                 await MainActor.run {
                     services = result
                     isLoadingServices = false
@@ -220,6 +232,7 @@ struct ConnectionEditSection: View {
     // MARK: - Persistence
 
     private func loadFromConnection() {
+        saveError = nil
         name = connection.config.name
         namespace = connection.config.namespace
         service = connection.config.service
@@ -232,6 +245,7 @@ struct ConnectionEditSection: View {
         useDirectExec = connection.config.useDirectExec
         notifyOnConnect = connection.config.notifyOnConnect
         notifyOnDisconnect = connection.config.notifyOnDisconnect
+        kubectlVerbosity = connection.config.kubectlVerbosity
     }
 
     private func saveToConnection() {
@@ -247,8 +261,14 @@ struct ConnectionEditSection: View {
             autoReconnect: autoReconnect,
             useDirectExec: useDirectExec,
             notifyOnConnect: notifyOnConnect,
-            notifyOnDisconnect: notifyOnDisconnect
+            notifyOnDisconnect: notifyOnDisconnect,
+            kubectlVerbosity: kubectlVerbosity
         )
-        appState.portForwardManager.updateConnection(newConfig)
+        do {
+            try appState.portForwardManager.updateConnection(newConfig)
+            saveError = nil
+        } catch {
+            saveError = error.localizedDescription
+        }
     }
 }
@@ -7,6 +7,7 @@ struct OptionsSection: View {
     @Binding var isEnabled: Bool
     @Binding var notifyOnConnect: Bool
     @Binding var notifyOnDisconnect: Bool
+    @Binding var kubectlVerbosity: Int
 
     var body: some View {
         VStack(alignment: .leading, spacing: 12) {
@@ -62,6 +63,22 @@ struct OptionsSection: View {
                 Spacer()
             }
             .font(.callout)
+
+            HStack(spacing: 8) {
+                Stepper(
+                    value: $kubectlVerbosity,
+                    in: PortForwardConnectionConfig.kubectlVerbosityRange
+                ) {
+                    Label(
+                        kubectlVerbosity == 0 ? "kubectl Verbosity: Off" : "kubectl Verbosity: -v=\(kubectlVerbosity)",
+                        systemImage: "text.magnifyingglass"
+                    )
+                }
+                .help("Pass -v=<n> to kubectl port-forward and show its output in the logs")
+
+                Spacer()
+            }
+            .font(.callout)
         }
     }
 }
@@ -16,6 +16,8 @@ struct PortForwardConnectionEditForm: View {
     @State private var useDirectExec: Bool
     @State private var notifyOnConnect: Bool
     @State private var notifyOnDisconnect: Bool
+    /// Why the current edits weren't saved (the last valid config stays in effect)
+    @State private var saveError: String?
 
     init(connection: PortForwardConnectionState) {
         self.connection = connection
@@ -144,6 +146,12 @@ struct PortForwardConnectionEditForm: View {
                     .toggleStyle(.checkbox)
                 }
             }
+
+            if let saveError {
+                Label(saveError, systemImage: "exclamationmark.triangle.fill")
+                    .font(.caption)
+                    .foregroundStyle(.red)
+            }
         }
     }
 
@@ -160,6 +168,11 @@ struct PortForwardConnectionEditForm: View {
         config.useDirectExec = useDirectExec
         config.notifyOnConnect = notifyOnConnect
         config.notifyOnDisconnect = notifyOnDisconnect
-        appState.portForwardManager.updateConnection(config)
+        do {
+            try appState.portForwardManager.updateConnection(config)
+            saveError = nil
+        } catch {
+            saveError = error.localizedDescription
+        }
     }
 }
@@ -52,7 +52,7 @@ struct PortForwarderSidebarContent: View {
             ServiceBrowserView(
                 discoveryManager: dm,
                 onServiceSelected: { config in
-                    appState.portForwardManager.addConnection(config)
+                    try appState.portForwardManager.addConnection(config)
                     discoveryManager = nil
                 },
                 onCancel: {
@@ -142,6 +142,7 @@ struct PortForwarderSidebarContent: View {
 struct AddConnectionButtons: View {
     @Environment(AppState.self) private var appState
     @Binding var discoveryManager: KubernetesDiscoveryManager?
+    @State private var configError: String?
 
     var body: some View {
         HStack(spacing: 16) {
@@ -154,7 +155,11 @@ struct AddConnectionButtons: View {
                     localPort: 8080,
                     remotePort: 80
                 )
-                appState.portForwardManager.addConnection(config)
+                do {
+                    try appState.portForwardManager.addConnection(config)
+                } catch {
+                    configError = error.localizedDescription
+                }
             } label: {
                 HStack(spacing: 6) {
                     Image(systemName: "plus.circle.fill")
@@ -180,5 +185,6 @@ struct AddConnectionButtons: View {
             .disabled(!DependencyChecker.shared.allRequiredInstalled)
         }
         .padding(.top, 4)
+        .connectionConfigErrorAlert($configError)
     }
 }
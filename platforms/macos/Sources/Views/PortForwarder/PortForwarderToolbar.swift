@@ -5,6 +5,7 @@ struct PortForwarderToolbar: View {
     @Binding var searchText: String
     @Binding var groupByNamespace: Bool
     @Binding var discoveryManager: KubernetesDiscoveryManager?
+    @State private var configError: String?
 
     var body: some View {
         HStack(spacing: 12) {
@@ -52,7 +53,7 @@ struct PortForwarderToolbar: View {
                 .disabled(manager.allConnected)
 
                 Button {
-                    manager.stopAll()
+                    Task { await manager.stopAll() }
                 } label: {
                     Label("Stop All", systemImage: "stop.fill")
                 }
@@ -76,7 +77,11 @@ struct PortForwarderToolbar: View {
                     localPort: 8080,
                     remotePort: 80
                 )
-                appState.portForwardManager.addConnection(config)
+                do {
+                    try appState.portForwardManager.addConnection(config)
+                } catch {
+                    configError = error.localizedDescription
+                }
             } label: {
                 Label("Add", systemImage: "plus.circle.fill")
             }
@@ -94,5 +99,6 @@ struct PortForwarderToolbar: View {
         }
         .padding(.horizontal, 12)
         .padding(.vertical, 8)
+        .connectionConfigErrorAlert($configError)
     }
 }
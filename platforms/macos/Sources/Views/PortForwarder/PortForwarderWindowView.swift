@@ -27,7 +27,7 @@ struct PortForwarderWindowView: View {
             ServiceBrowserView(
                 discoveryManager: dm,
                 onServiceSelected: { config in
-                    appState.portForwardManager.addConnection(config)
+                    try appState.portForwardManager.addConnection(config)
                     discoveryManager = nil
                 },
                 onCancel: {
@@ -2,9 +2,12 @@ import SwiftUI
 
 struct ServiceBrowserView: View {
     @Bindable var discoveryManager: KubernetesDiscoveryManager
-    let onServiceSelected: (PortForwardConnectionConfig) -> Void
+    /// Adds the connection; throwing keeps the sheet open and shows the error
+    let onServiceSelected: (PortForwardConnectionConfig) throws -> Void
     let onCancel: () -> Void
 
+    @State private var configError: String?
+
     var body: some View {
         VStack(spacing: 0) {
             // Header
@@ -31,6 +34,9 @@ struct ServiceBrowserView: View {
                     namespaces: discoveryManager.namespaces,
                     selectedNamespace: discoveryManager.selectedNamespace,
                     state: discoveryManager.namespaceState,
+                    currentContext: discoveryManager.currentContext,
+                    contexts: discoveryManager.contexts,
+                    onLoadContexts: { await discoveryManager.loadContexts() },
                     onSelect: { namespace in
                         Task { await discoveryManager.selectNamespace(namespace) }
                     },
@@ -101,7 +107,11 @@ struct ServiceBrowserView: View {
 
                 Button("Add") {
                     if let config = discoveryManager.createConnectionConfig() {
-                        onServiceSelected(config)
+                        do {
+                            try onServiceSelected(config)
+                        } catch {
+                            configError = error.localizedDescription
+                        }
                     }
                 }
                 .keyboardShortcut(.defaultAction)
@@ -110,6 +120,7 @@ struct ServiceBrowserView: View {
             .padding()
         }
         .frame(width: 800, height: 500)
+        .connectionConfigErrorAlert($configError)
         .task {
             if discoveryManager.namespaceState == .idle {
                 await discoveryManager.loadNamespaces()
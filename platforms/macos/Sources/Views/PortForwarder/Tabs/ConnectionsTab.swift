@@ -4,6 +4,7 @@ struct ConnectionsTab: View {
     @Environment(AppState.self) private var appState
     @Binding var discoveryManager: KubernetesDiscoveryManager?
     @State private var selectedConnectionId: UUID?
+    @State private var configError: String?
 
     private var selectedConnection: PortForwardConnectionState? {
         guard let id = selectedConnectionId else { return nil }
@@ -29,7 +30,11 @@ struct ConnectionsTab: View {
                             localPort: 8080,
                             remotePort: 80
                         )
-                        appState.portForwardManager.addConnection(config)
+                        do {
+                            try appState.portForwardManager.addConnection(config)
+                        } catch {
+                            configError = error.localizedDescription
+                        }
                     } label: {
                         Label("Add", systemImage: "plus.circle.fill")
                     }
@@ -81,6 +86,10 @@ struct ConnectionsTab: View {
                         Text("\(manager.connectedCount) of \(manager.connections.count) connected")
                     }
 
+                    if let summary = manager.lastStopSummary {
+                        Text("· \(summary.message)")
+                    }
+
                     Spacer()
 
                     if manager.isKillingProcesses {
@@ -102,7 +111,7 @@ struct ConnectionsTab: View {
                         .disabled(manager.allConnected)
 
                         Button("Stop All") {
-                            manager.stopAll()
+                            Task { await manager.stopAll() }
                         }
                         .buttonStyle(.bordered)
                         .disabled(manager.connectedCount == 0)
@@ -120,5 +129,6 @@ struct ConnectionsTab: View {
             ConnectionLogPanel(connection: selectedConnection)
                 .frame(minWidth: 450)
         }
+        .connectionConfigErrorAlert($configError)
     }
 }
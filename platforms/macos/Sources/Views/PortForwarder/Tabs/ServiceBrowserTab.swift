@@ -10,7 +10,7 @@ struct ServiceBrowserTab: View {
                 ServiceBrowserEmbedded(
                     discoveryManager: dm,
                     onServiceSelected: { config in
-                        appState.portForwardManager.addConnection(config)
+                        try appState.portForwardManager.addConnection(config)
                     }
                 )
             } else {
@@ -49,7 +49,9 @@ struct ServiceBrowserTab: View {
 
 struct ServiceBrowserEmbedded: View {
     @Bindable var discoveryManager: KubernetesDiscoveryManager
-    let onServiceSelected: (PortForwardConnectionConfig) -> Void
+    let onServiceSelected: (PortForwardConnectionConfig) throws -> Void
+
+    @State private var configError: String?
 
     var body: some View {
         VStack(spacing: 0) {
@@ -60,6 +62,9 @@ struct ServiceBrowserEmbedded: View {
                     namespaces: discoveryManager.namespaces,
                     selectedNamespace: discoveryManager.selectedNamespace,
                     state: discoveryManager.namespaceState,
+                    currentContext: discoveryManager.currentContext,
+                    contexts: discoveryManager.contexts,
+                    onLoadContexts: { await discoveryManager.loadContexts() },
                     onSelect: { namespace in
                         Task { await discoveryManager.selectNamespace(namespace) }
                     },
@@ -101,12 +106,17 @@ struct ServiceBrowserEmbedded: View {
                     },
                     onAdd: {
                         if let config = discoveryManager.createConnectionConfig() {
-                            onServiceSelected(config)
+                            do {
+                                try onServiceSelected(config)
+                            } catch {
+                                configError = error.localizedDescription
+                            }
                         }
                     }
                 )
                 .frame(width: 250)
             }
         }
+        .connectionConfigErrorAlert($configError)
     }
 }
@@ -2,6 +2,7 @@
 ///
 /// Displays general settings including:
 /// - Launch at login toggle
+/// - Hostname resolution toggle
 ///
 /// - Note: Uses LaunchAtLogin package for login item management.
 
@@ -12,6 +13,7 @@ import Defaults
 struct GeneralSettingsSection: View {
     @Default(.hideSystemProcesses) private var hideSystemProcesses
     @Default(.skipKillConfirmation) private var skipKillConfirmation
+    @Default(.resolveHostnames) private var resolveHostnames
 
     var body: some View {
         SettingsGroup("General", icon: "gearshape.fill") {
@@ -41,6 +43,14 @@ struct GeneralSettingsSection: View {
                 subtitle: "Kill processes immediately without confirmation prompt",
                 isOn: $skipKillConfirmation
             )
+
+            SettingsDivider()
+
+            SettingsToggleRow(
+                title: "Resolve hostnames",
+                subtitle: "Look up hostnames for non-loopback bound addresses (slower scans)",
+                isOn: $resolveHostnames
+            )
         }
     }
 }
@@ -0,0 +1,212 @@
+import Testing
+import Foundation
+import Synchronization
+import Defaults
+@testable import PortKiller
+
+/**
+ * Tests for keeping the cached port list truthful after kills.
+ */
+@MainActor
+struct AppStateKillTests {
+
+    // MARK: - Test Fixtures
+
+    /// Node (PID 100) holds 3000 and 9229; postgres (PID 200) holds 5432.
+    var populated: [PortInfo] {
+        [
+            createPort(port: 3000, pid: 100),
+            createPort(port: 5432, pid: 200, processName: "postgres"),
+            createPort(port: 9229, pid: 100)
+        ]
+    }
+
+    var afterKill: [PortInfo] {
+        [createPort(port: 5432, pid: 200, processName: "postgres")]
+    }
+
+    // MARK: - Cache Invalidation
+
+    @Test("Killing a port removes every row for that PID before the next scan lands")
+    func killRemovesRowsBeforeRefresh() async {
+        let scanner = MockPortScanner(snapshots: [populated, afterKill], heldScans: [2])
+        let appState = await createAppState(scanner: scanner)
+        #expect(appState.ports.count == 3)
+
+        let target = appState.ports.first { $0.port == 3000 }!
+        async let kill: Void = appState.killPort(target)
+        // The post-kill refresh is now in flight and held.
+        await waitForScans(scanner, count: 2)
+
+        #expect(appState.ports.map(\.port) == [5432])
+        #expect(await scanner.killedPids == [100])
+
+        await scanner.releaseScan(2)
+        await kill
+        #expect(appState.ports.map(\.port) == [5432])
+    }
+
+    @Test("A kill that lands while a scan is resolving hostnames still drops the killed rows")
+    func killDuringHostnameResolutionDoesNotResurrectRows() async {
+        Defaults[.resolveHostnames] = true
+        defer { Defaults[.resolveHostnames] = false }
+
+        let lookup = HeldLookup()
+        let resolver = HostnameResolver(timeout: .seconds(30)) { _ in lookup.wait() }
+        // Scan 2 also reports node on a LAN address, so its pass stops to resolve it.
+        let remote = populated + [createPort(port: 8080, pid: 100, address: "192.168.1.10")]
+        let scanner = MockPortScanner(snapshots: [populated, remote, afterKill], heldScans: [3])
+        let appState = await createAppState(scanner: scanner, hostnameResolver: resolver)
+
+        async let background = appState.refresh()
+        while !lookup.started {
+            await Task.yield()
+        }
+
+        let target = appState.ports.first { $0.port == 3000 }!
+        async let kill: Void = appState.killPort(target)
+        while await scanner.killedPids.isEmpty {
+            await Task.yield()
+        }
+
+        lookup.release()
+        // Scan 3 starts only once scan 2's results are applied.
+        await waitForScans(scanner, count: 3)
+        #expect(appState.ports.map(\.port) == [5432])
+
+        await scanner.releaseScan(3)
+        _ = await background
+        await kill
+        #expect(appState.ports.map(\.port) == [5432])
+    }
+
+    @Test("A scan already in flight when the kill happens can't restore the killed rows")
+    func staleInFlightScanDoesNotResurrectKilledRows() async {
+        // Scan 2 started before the kill, so it still reports PID 100.
+        let scanner = MockPortScanner(snapshots: [populated, populated, afterKill], heldScans: [2, 3])
+        let appState = await createAppState(scanner: scanner)
+
+        async let background = appState.refresh()
+        await waitForScans(scanner, count: 2)
+
+        let target = appState.ports.first { $0.port == 3000 }!
+        async let kill: Void = appState.killPort(target)
+        while await scanner.killedPids.isEmpty {
+            await Task.yield()
+        }
+        #expect(appState.ports.map(\.port) == [5432])
+
+        // Let the stale scan land; the forced follow-up (scan 3) starts right after.
+        await scanner.releaseScan(2)
+        await waitForScans(scanner, count: 3)
+        #expect(!appState.ports.contains { $0.pid == 100 })
+
+        await scanner.releaseScan(3)
+        _ = await background
+        await kill
+        #expect(appState.ports.map(\.port) == [5432])
+    }
+
+    @Test("A failed kill leaves the process's rows in place")
+    func failedKillKeepsRows() async {
+        // Postgres (PID 200) is root-owned here: its kill fails and it keeps listening.
+        let scanner = MockPortScanner(snapshots: [populated, afterKill], heldScans: [2], unkillablePids: [200])
+        let appState = await createAppState(scanner: scanner)
+
+        async let kill: Void = appState.killAll()
+        await waitForScans(scanner, count: 2)
+
+        #expect(appState.ports.map(\.port) == [5432])
+        #expect(await scanner.killedPids == [100])
+
+        await scanner.releaseScan(2)
+        await kill
+        #expect(appState.ports.map(\.port) == [5432])
+    }
+
+    @Test("A failed deep kill doesn't hide the listener from the in-flight scan")
+    func failedDeepKillKeepsListener() async {
+        let scanner = MockPortScanner(snapshots: [populated], heldScans: [2], unkillablePids: [100])
+        let appState = await createAppState(scanner: scanner)
+
+        let target = appState.ports.first { $0.port == 3000 }!
+        async let kill: Void = appState.killPortDeep(target)
+        await waitForScans(scanner, count: 2)
+        #expect(appState.ports.map(\.port) == [3000, 5432, 9229])
+
+        await scanner.releaseScan(2)
+        await kill
+        #expect(appState.ports.map(\.port) == [3000, 5432, 9229])
+    }
+
+    // MARK: - Repeat Offenders
+
+    @Test("A port that respawns after rapid kills is flagged as a repeat offender")
+    func respawningPortIsRepeatOffender() async {
+        // Each scan finds port 3000 again under a new PID, like a supervisor restarting it.
+        let scanner = MockPortScanner(snapshots: (100...103).map { [createPort(port: 3000, pid: $0)] })
+        let appState = await createAppState(scanner: scanner)
+
+        for _ in 0..<2 {
+            await appState.killPort(appState.ports[0])
+        }
+        #expect(!appState.isRepeatOffender(3000))
+
+        await appState.killPort(appState.ports[0])
+        #expect(await scanner.killedPids == [100, 101, 102])
+        #expect(appState.ports.map(\.pid) == [103])
+        #expect(appState.isRepeatOffender(3000))
+    }
+
+    @Test("A port that stays dead is not flagged, however often it was killed")
+    func deadPortIsNotRepeatOffender() async {
+        let scanner = MockPortScanner(snapshots: [[createPort(port: 3000, pid: 100)], []])
+        let appState = await createAppState(scanner: scanner)
+
+        await appState.killPort(appState.ports[0])
+        for _ in 0..<2 {
+            appState.killHistory.record(port: 3000)
+        }
+
+        #expect(appState.killHistory.isRepeatOffender(port: 3000))
+        #expect(!appState.isRepeatOffender(3000))
+    }
+
+    @Test("Kills that fail don't count toward the repeat-offender flag")
+    func failedKillsAreNotRecorded() async {
+        let scanner = MockPortScanner(snapshots: [populated], unkillablePids: [200])
+        let appState = await createAppState(scanner: scanner)
+        let postgres = appState.ports.first { $0.pid == 200 }!
+
+        for _ in 0..<3 {
+            await appState.killAll()
+            await appState.killPortDeep(postgres)
+            await appState.killPort(postgres)
+        }
+
+        #expect(appState.killHistory.recentKillCount(port: 5432) == 0)
+        #expect(!appState.isRepeatOffender(5432))
+        // PID 100 really was killed each round and respawned with the same snapshot.
+        #expect(appState.isRepeatOffender(3000))
+    }
+}
+
+/// A reverse lookup that blocks until the test releases it.
+private final class HeldLookup: Sendable {
+    private let didStart = Mutex(false)
+    private let gate = DispatchSemaphore(value: 0)
+
+    var started: Bool {
+        didStart.withLock { $0 }
+    }
+
+    func wait() -> String? {
+        didStart.withLock { $0 = true }
+        gate.wait()
+        return "build-box.local"
+    }
+
+    func release() {
+        gate.signal()
+    }
+}
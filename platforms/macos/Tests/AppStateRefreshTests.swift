@@ -0,0 +1,113 @@
+import Testing
+@testable import PortKiller
+
+/**
+ * Tests for AppState refresh coalescing.
+ *
+ * Background refreshes may be coalesced into an in-flight scan, but a
+ * user-initiated (forced) refresh must always be followed by fresh data.
+ */
+@MainActor
+struct AppStateRefreshTests {
+
+    // MARK: - Forced Refresh
+
+    @Test("Forced refresh during an in-flight scan produces an updated snapshot")
+    func forcedRefreshDuringInFlightScan() async {
+        let stale = [createPort(port: 3000, pid: 100)]
+        let fresh = [createPort(port: 3000, pid: 100), createPort(port: 8080, pid: 200)]
+        let scanner = MockPortScanner(snapshots: [stale, fresh], heldScans: [1])
+
+        let appState = AppState(scanner: scanner)
+        // The auto-refresh task still performs its initial scan, which stays in flight.
+        appState.stopAutoRefresh()
+        await waitForScans(scanner, count: 1)
+        #expect(appState.isScanning)
+
+        async let forced: Void = appState.refreshForced()
+        await scanner.releaseScan(1)
+        await forced
+
+        #expect(await scanner.scanCount == 2)
+        #expect(appState.ports.map(\.port) == [3000, 8080])
+    }
+
+    @Test("Background refresh during an in-flight scan queues one follow-up without waiting")
+    func backgroundRefreshQueuesFollowUp() async {
+        let ports = [createPort(port: 3000, pid: 100)]
+        let scanner = MockPortScanner(snapshots: [ports], heldScans: [1])
+
+        let appState = AppState(scanner: scanner)
+        appState.stopAutoRefresh()
+        await waitForScans(scanner, count: 1)
+
+        let didChange = await appState.refresh()
+        #expect(!didChange)
+        _ = await appState.refresh()
+
+        await scanner.releaseScan(1)
+        await waitForScans(scanner, count: 2)
+        while appState.isScanning {
+            await Task.yield()
+        }
+
+        #expect(await scanner.scanCount == 2)
+    }
+
+    // MARK: - Scanner Backend
+
+    @Test("The last scan's backend is exposed, so an unavailable lsof isn't mistaken for no ports")
+    func lastScanBackendTracksScanner() async {
+        let scanner = MockPortScanner(snapshots: [[createPort(port: 3000, pid: 100)]], unavailableScans: [2])
+
+        let appState = await createAppState(scanner: scanner)
+        #expect(appState.lastScanBackend == .lsof)
+
+        await appState.refresh()
+        #expect(appState.lastScanBackend == .unavailable)
+        #expect(appState.ports.isEmpty)
+
+        await appState.refresh()
+        #expect(appState.lastScanBackend == .lsof)
+    }
+
+    // MARK: - Freshness
+
+    @Test("Refresh age is nil until the first scan completes, then small")
+    func refreshAgeAfterFirstScan() async throws {
+        let scanner = MockPortScanner(snapshots: [[createPort(port: 3000, pid: 100)]], heldScans: [1])
+
+        let appState = AppState(scanner: scanner)
+        appState.stopAutoRefresh()
+        await waitForScans(scanner, count: 1)
+
+        #expect(appState.lastRefreshTime == nil)
+        #expect(appState.lastRefreshAge == nil)
+
+        await scanner.releaseScan(1)
+        while appState.isScanning {
+            await Task.yield()
+        }
+
+        let age = try #require(appState.lastRefreshAge)
+        #expect(age >= .zero)
+        #expect(age < .seconds(5))
+        #expect(appState.lastRefreshTime != nil)
+    }
+
+    @Test("A scan where lsof couldn't run doesn't count as a refresh")
+    func unavailableScanKeepsPreviousRefreshTime() async throws {
+        let scanner = MockPortScanner(snapshots: [[createPort(port: 3000, pid: 100)]], unavailableScans: [2])
+
+        let appState = await createAppState(scanner: scanner)
+        let firstInstant = try #require(appState.lastRefreshInstant)
+        let firstTime = try #require(appState.lastRefreshTime)
+
+        await appState.refresh()
+        #expect(appState.lastRefreshInstant == firstInstant)
+        #expect(appState.lastRefreshTime == firstTime)
+
+        await appState.refresh()
+        #expect(try #require(appState.lastRefreshInstant) > firstInstant)
+    }
+}
@@ -0,0 +1,112 @@
+import Testing
+import Foundation
+import Synchronization
+@testable import PortKiller
+
+struct HostnameResolverTests {
+
+    // MARK: - Address Filtering
+
+    @Test("Wildcard, loopback and placeholder addresses are not resolved")
+    func skipsWildcardAndLoopback() {
+        for address in ["*", "-", "0.0.0.0", "[::]", "127.0.0.1", "[::1]", "localhost"] {
+            #expect(!HostnameResolver.isResolvable(address), "\(address) should be skipped")
+        }
+        #expect(HostnameResolver.isResolvable("192.168.1.20"))
+        #expect(HostnameResolver.isResolvable("[fe80::1]"))
+    }
+
+    // MARK: - Annotation
+
+    @Test("Annotates a known IP using the injected resolver")
+    func annotatesKnownAddress() async {
+        let resolver = HostnameResolver { host in
+            host == "192.168.1.20" ? "devbox.local" : nil
+        }
+        let ports = [
+            createPort(port: 3000, address: "192.168.1.20"),
+            createPort(port: 4000, address: "127.0.0.1"),
+            createPort(port: 5000, address: "10.0.0.9")
+        ]
+
+        let annotated = await resolver.annotate(ports)
+
+        #expect(annotated.map(\.hostname) == ["devbox.local", nil, nil])
+    }
+
+    @Test("Strips IPv6 brackets before looking up")
+    func stripsIPv6Brackets() async {
+        let resolver = HostnameResolver { host in
+            host == "fe80::1" ? "router.local" : nil
+        }
+
+        let annotated = await resolver.annotate([createPort(port: 3000, address: "[fe80::1]")])
+
+        #expect(annotated.first?.hostname == "router.local")
+    }
+
+    @Test("A hanging lookup times out without blocking the scan")
+    func slowLookupTimesOut() async {
+        // Blocks a GCD thread (as getnameinfo would) until the test releases it.
+        let gate = DispatchSemaphore(value: 0)
+        defer { gate.signal() }
+        let resolver = HostnameResolver(timeout: .milliseconds(100)) { _ in
+            _ = gate.wait(timeout: .now() + 5)
+            return "too-late.local"
+        }
+        let ports = [createPort(port: 3000, address: "192.168.1.20")]
+
+        let clock = ContinuousClock()
+        let start = clock.now
+        let annotated = await resolver.annotate(ports)
+        let elapsed = clock.now - start
+
+        #expect(annotated.first?.hostname == nil)
+        #expect(elapsed < .seconds(1))
+    }
+
+    @Test("A timed-out lookup isn't cached as a miss; its late answer is")
+    func lateAnswerFillsCache() async throws {
+        let gate = DispatchSemaphore(value: 0)
+        let resolver = HostnameResolver(timeout: .milliseconds(50)) { _ in
+            _ = gate.wait(timeout: .now() + 5)
+            return "late.local"
+        }
+
+        #expect(await resolver.resolve("192.168.1.20") == nil)
+
+        gate.signal()
+        var hostname: String?
+        for _ in 0..<200 where hostname == nil {
+            hostname = await resolver.resolve("192.168.1.20")
+            try await Task.sleep(for: .milliseconds(10))
+        }
+        #expect(hostname == "late.local")
+    }
+
+    @Test("Missing PTR records are cached and not looked up again")
+    func missesAreCached() async {
+        let calls = CallCounter()
+        let resolver = HostnameResolver { _ in
+            calls.increment()
+            return nil
+        }
+
+        #expect(await resolver.resolve("10.0.0.9") == nil)
+        #expect(await resolver.resolve("10.0.0.9") == nil)
+        #expect(calls.value == 1)
+    }
+}
+
+/// Counts lookups from the synchronous resolver closure.
+private final class CallCounter: Sendable {
+    private let count = Mutex(0)
+
+    func increment() {
+        count.withLock { $0 += 1 }
+    }
+
+    var value: Int {
+        count.withLock { $0 }
+    }
+}
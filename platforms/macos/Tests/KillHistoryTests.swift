@@ -0,0 +1,60 @@
+import Foundation
+import Testing
+@testable import PortKiller
+
+/**
+ * Tests for per-port kill history and repeat-offender detection.
+ */
+struct KillHistoryTests {
+
+    let start = Date(timeIntervalSince1970: 1_700_000_000)
+
+    @Test("Three kills within a minute trip the offender flag")
+    func rapidKillsTripFlag() {
+        var history = KillHistory()
+        history.record(port: 3000, at: start)
+        history.record(port: 3000, at: start.addingTimeInterval(5))
+        #expect(!history.isRepeatOffender(port: 3000, now: start.addingTimeInterval(10)))
+
+        history.record(port: 3000, at: start.addingTimeInterval(10))
+        #expect(history.isRepeatOffender(port: 3000, now: start.addingTimeInterval(10)))
+    }
+
+    @Test("Kills older than the window don't count")
+    func oldKillsExpire() {
+        var history = KillHistory()
+        history.record(port: 3000, at: start)
+        history.record(port: 3000, at: start.addingTimeInterval(1))
+        history.record(port: 3000, at: start.addingTimeInterval(2))
+
+        #expect(history.isRepeatOffender(port: 3000, now: start.addingTimeInterval(30)))
+        #expect(!history.isRepeatOffender(port: 3000, now: start.addingTimeInterval(120)))
+        #expect(history.recentKillCount(port: 3000, now: start.addingTimeInterval(61.5)) == 1)
+    }
+
+    @Test("History is tracked per port")
+    func historyIsPerPort() {
+        var history = KillHistory()
+        for offset in 0..<3 {
+            history.record(port: 3000, at: start.addingTimeInterval(Double(offset)))
+        }
+        history.record(port: 8080, at: start)
+
+        #expect(history.isRepeatOffender(port: 3000, now: start.addingTimeInterval(3)))
+        #expect(!history.isRepeatOffender(port: 8080, now: start.addingTimeInterval(3)))
+        #expect(history.lastKill(port: 8080) == start)
+        #expect(history.lastKill(port: 5432) == nil)
+    }
+
+    @Test("Only the most recent kills are kept")
+    func ringBufferEvictsOldest() {
+        var history = KillHistory()
+        for offset in 0..<(KillHistory.capacity + 5) {
+            history.record(port: 3000, at: start.addingTimeInterval(Double(offset)))
+        }
+
+        let now = start.addingTimeInterval(Double(KillHistory.capacity + 5))
+        #expect(history.recentKillCount(port: 3000, now: now) == KillHistory.capacity)
+        #expect(history.lastKill(port: 3000) == start.addingTimeInterval(Double(KillHistory.capacity + 4)))
+    }
+}
@@ -0,0 +1,145 @@
+import Foundation
+import Testing
+@testable import PortKiller
+
+struct KubernetesModelsTests {
+
+    // MARK: - Context Parsing
+
+    @Test("Parses one context name per line")
+    func parsesContextNames() {
+        let output = "docker-desktop\nkind-dev\nprod-eu-west-1"
+        #expect(KubernetesContext.parseNames(from: output) == ["docker-desktop", "kind-dev", "prod-eu-west-1"])
+    }
+
+    @Test("Drops the trailing newline from kubectl output")
+    func dropsTrailingNewline() {
+        let output = "docker-desktop\nkind-dev\n"
+        #expect(KubernetesContext.parseNames(from: output) == ["docker-desktop", "kind-dev"])
+    }
+
+    @Test("Empty output yields no contexts")
+    func emptyOutputYieldsNoContexts() {
+        #expect(KubernetesContext.parseNames(from: "").isEmpty)
+        #expect(KubernetesContext.parseNames(from: "\n").isEmpty)
+    }
+
+    @Test("Ignores blank lines and surrounding whitespace")
+    func ignoresBlankLinesAndWhitespace() {
+        let output = "  docker-desktop  \n\n\tkind-dev\r\n"
+        #expect(KubernetesContext.parseNames(from: output) == ["docker-desktop", "kind-dev"])
+    }
+
+    // MARK: - Service Parsing
+
+    /// `kubectl get services -o json` output covering the port-less edge cases.
+    let servicesJSON = """
+    {
+      "items": [
+        {
+          "metadata": { "name": "api", "namespace": "default" },
+          "spec": {
+            "type": "ClusterIP",
+            "clusterIP": "10.96.0.12",
+            "ports": [{ "name": "http", "port": 80, "targetPort": 8080, "protocol": "TCP" }]
+          }
+        },
+        {
+          "metadata": { "name": "postgres", "namespace": "default" },
+          "spec": {
+            "type": "ClusterIP",
+            "clusterIP": "None",
+            "ports": [{ "port": 5432, "targetPort": "postgres" }]
+          }
+        },
+        {
+          "metadata": { "name": "selectorless", "namespace": "default" },
+          "spec": { "type": "ClusterIP", "clusterIP": "None" }
+        },
+        {
+          "metadata": { "name": "billing", "namespace": "default" },
+          "spec": { "type": "ExternalName", "externalName": "billing.example.com" }
+        },
+        {
+          "metadata": { "name": "legacy", "namespace": "default" },
+          "spec": { "clusterIP": "10.96.0.40" }
+        }
+      ]
+    }
+    """
+
+    func parseServices() throws -> [KubernetesService] {
+        let response = try JSONDecoder().decode(KubernetesService.ListResponse.self, from: Data(servicesJSON.utf8))
+        return KubernetesService.from(response: response)
+    }
+
+    func service(named name: String, in services: [KubernetesService]) throws -> KubernetesService {
+        try #require(services.first { $0.name == name })
+    }
+
+    @Test("A regular service is forwardable")
+    func regularServiceIsForwardable() throws {
+        let api = try service(named: "api", in: parseServices())
+
+        #expect(!api.isHeadless)
+        #expect(api.hasForwardablePorts)
+        #expect(api.unforwardableReason == nil)
+        #expect(api.ports.map(\.targetPort) == [8080])
+    }
+
+    @Test("A headless service with ports is still forwardable")
+    func headlessServiceWithPorts() throws {
+        let postgres = try service(named: "postgres", in: parseServices())
+
+        #expect(postgres.isHeadless)
+        #expect(postgres.hasForwardablePorts)
+        // Named target ports fall back to the service port.
+        #expect(postgres.ports.map(\.targetPort) == [5432])
+    }
+
+    @Test("Missing ports parse as empty and are explained")
+    func missingPortsAreExplained() throws {
+        let services = try parseServices()
+        let selectorless = try service(named: "selectorless", in: services)
+        let legacy = try service(named: "legacy", in: services)
+
+        #expect(selectorless.ports.isEmpty)
+        #expect(!selectorless.hasForwardablePorts)
+        #expect(selectorless.unforwardableReason == "Headless service with no ports defined")
+
+        #expect(legacy.type == "ClusterIP")
+        #expect(legacy.unforwardableReason == "No ports defined")
+    }
+
+    @Test("ExternalName services keep their target and are not forwardable")
+    func externalNameIsNotForwardable() throws {
+        let billing = try service(named: "billing", in: parseServices())
+
+        #expect(billing.isExternalName)
+        #expect(billing.externalName == "billing.example.com")
+        #expect(billing.clusterIP == nil)
+        #expect(!billing.hasForwardablePorts)
+        #expect(billing.unforwardableReason?.contains("billing.example.com") == true)
+    }
+
+    @Test("Fetching services with forwardableOnly keeps only usable services")
+    func fetchServicesForwardableOnly() async throws {
+        let directory = FileManager.default.temporaryDirectory
+            .appendingPathComponent("fake-kubectl-\(UUID().uuidString)")
+        try FileManager.default.createDirectory(at: directory, withIntermediateDirectories: true)
+        defer { try? FileManager.default.removeItem(at: directory) }
+
+        // A kubectl that answers every call with the fixture, so the real decode-and-filter path runs.
+        let fixture = directory.appendingPathComponent("services.json").path
+        try servicesJSON.write(toFile: fixture, atomically: true, encoding: .utf8)
+        let kubectl = directory.appendingPathComponent("kubectl").path
+        let processManager = PortForwardProcessManager(kubectlPath: { kubectl })
+        try processManager.writeExecutableScript("#!/bin/sh\nexec /bin/cat '\(fixture)'\n", to: kubectl)
+
+        let all = try await processManager.fetchServices(namespace: "default")
+        #expect(all.map(\.name) == ["api", "billing", "legacy", "postgres", "selectorless"])
+
+        let forwardable = try await processManager.fetchServices(namespace: "default", forwardableOnly: true)
+        #expect(forwardable.map(\.name) == ["api", "postgres"])
+    }
+}
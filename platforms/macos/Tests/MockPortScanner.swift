@@ -0,0 +1,77 @@
+import Foundation
+@testable import PortKiller
+
+/**
+ * In-memory PortScannerProtocol used to drive AppState without lsof.
+ *
+ * Each scan returns the next queued snapshot (repeating the last one once
+ * exhausted). Scans listed in `heldScans` (1-based) suspend until
+ * `releaseScan(_:)` is called so tests can act while a scan is in flight.
+ * Kills of `unkillablePids` fail, like a root-owned process would, and scans
+ * listed in `unavailableScans` report the `.unavailable` backend.
+ */
+actor MockPortScanner: PortScannerProtocol {
+    private var snapshots: [[PortInfo]]
+    private var heldScans: Set<Int>
+    private var gates: [Int: CheckedContinuation<Void, Never>] = [:]
+    private let unkillablePids: Set<Int>
+    private let unavailableScans: Set<Int>
+    private var backend: ScannerBackend?
+
+    private(set) var scanCount = 0
+    /// PIDs whose kill succeeded, in order
+    private(set) var killedPids: [Int] = []
+
+    init(
+        snapshots: [[PortInfo]],
+        heldScans: Set<Int> = [],
+        unkillablePids: Set<Int> = [],
+        unavailableScans: Set<Int> = []
+    ) {
+        self.snapshots = snapshots
+        self.heldScans = heldScans
+        self.unkillablePids = unkillablePids
+        self.unavailableScans = unavailableScans
+    }
+
+    func scanPorts() async -> [PortInfo] {
+        scanCount += 1
+        let scanNumber = scanCount
+        let index = min(scanNumber, snapshots.count) - 1
+
+        if heldScans.contains(scanNumber) {
+            await withCheckedContinuation { gates[scanNumber] = $0 }
+        }
+
+        if unavailableScans.contains(scanNumber) {
+            backend = .unavailable
+            return []
+        }
+        backend = .lsof
+        return index >= 0 ? snapshots[index] : []
+    }
+
+    /// Lets held scan number `scanNumber` return (or not wait at all if it hasn't started).
+    func releaseScan(_ scanNumber: Int) {
+        heldScans.remove(scanNumber)
+        gates.removeValue(forKey: scanNumber)?.resume()
+    }
+
+    func killProcess(pid: Int, force: Bool) async -> Bool {
+        guard !unkillablePids.contains(pid) else { return false }
+        killedPids.append(pid)
+        return true
+    }
+
+    func killProcessGracefully(pid: Int) async -> Bool {
+        await killProcess(pid: pid, force: true)
+    }
+
+    func findEstablishedPids(for port: Int) async -> Set<Int> {
+        []
+    }
+
+    func lastBackend() -> ScannerBackend? {
+        backend
+    }
+}
@@ -10,28 +10,6 @@ import Testing
  */
 struct PortFilterTests {
 
-    // MARK: - Test Fixtures
-
-    /// Creates a sample active port for testing
-    func createPort(
-        port: Int = 3000,
-        pid: Int = 12345,
-        processName: String = "node",
-        address: String = "127.0.0.1",
-        user: String = "testuser",
-        command: String = "node server.js"
-    ) -> PortInfo {
-        PortInfo.active(
-            port: port,
-            pid: pid,
-            processName: processName,
-            address: address,
-            user: user,
-            command: command,
-            fd: "19u"
-        )
-    }
-
     // MARK: - isActive Tests
 
     @Test("Empty filter is not active")
@@ -304,4 +282,48 @@ struct PortFilterTests {
         let port = createPort(command: "node server.js")
         #expect(filter.matches(port, favorites: [], watched: []))
     }
+
+    // MARK: - Scan + Filter
+
+    var scanResult: [PortInfo] {
+        [
+            createPort(port: 3000, pid: 100, processName: "node", command: "node server.js"),
+            createPort(port: 5432, pid: 200, processName: "postgres", command: "postgres -D /data"),
+            createPort(port: 6379, pid: 300, processName: "redis-server", command: "redis-server *:6379"),
+            createPort(port: 8080, pid: 400, processName: "python3", command: "python3 -m http.server 8080")
+        ]
+    }
+
+    @Test("scanFiltered applies the process type filter to the scan")
+    func scanFilteredByType() async {
+        let scanner = MockPortScanner(snapshots: [scanResult])
+        let filter = PortFilter(processTypes: [.database])
+
+        let ports = await scanner.scanFiltered(filter)
+        #expect(ports.map(\.port) == [5432, 6379])
+    }
+
+    @Test("scanFiltered applies search text to the scan")
+    func scanFilteredBySearch() async {
+        let scanner = MockPortScanner(snapshots: [scanResult])
+        let filter = PortFilter(searchText: "http.server")
+
+        let ports = await scanner.scanFiltered(filter)
+        #expect(ports.map(\.port) == [8080])
+    }
+
+    @Test("scanFiltered uses the passed-in favorites for the favorites-only filter")
+    func scanFilteredByFavorites() async {
+        let scanner = MockPortScanner(snapshots: [scanResult])
+        let filter = PortFilter(showOnlyFavorites: true)
+
+        #expect(await scanner.scanFiltered(filter, favorites: [3000, 6379]).map(\.port) == [3000, 6379])
+        #expect(await scanner.scanFiltered(filter).isEmpty)
+    }
+
+    @Test("scanFiltered with an empty filter returns the whole scan")
+    func scanFilteredWithEmptyFilter() async {
+        let scanner = MockPortScanner(snapshots: [scanResult])
+        #expect(await scanner.scanFiltered(PortFilter()).count == 4)
+    }
 }
@@ -0,0 +1,163 @@
+import Testing
+@testable import PortKiller
+
+/**
+ * Tests for PortForwardConnectionConfig validation rules.
+ */
+struct PortForwardConnectionConfigTests {
+
+    // MARK: - Test Fixtures
+
+    func createConfig(
+        name: String = "api",
+        namespace: String = "default",
+        service: String = "api",
+        localPort: Int = 8080,
+        remotePort: Int = 80,
+        proxyPort: Int? = nil
+    ) -> PortForwardConnectionConfig {
+        PortForwardConnectionConfig(
+            name: name,
+            namespace: namespace,
+            service: service,
+            localPort: localPort,
+            remotePort: remotePort,
+            proxyPort: proxyPort
+        )
+    }
+
+    // MARK: - Validation
+
+    @Test("A complete config is valid")
+    func validConfig() {
+        let config = createConfig(proxyPort: 8079)
+        #expect(config.validate().isEmpty)
+        #expect(config.isValid)
+    }
+
+    @Test("Empty name is rejected")
+    func emptyName() {
+        #expect(createConfig(name: "").validate() == [.emptyName])
+        #expect(createConfig(name: "   ").validate() == [.emptyName])
+    }
+
+    @Test("Empty namespace is rejected")
+    func emptyNamespace() {
+        #expect(createConfig(namespace: "").validate() == [.emptyNamespace])
+    }
+
+    @Test("Empty service is rejected")
+    func emptyService() {
+        #expect(createConfig(service: "").validate() == [.emptyService])
+    }
+
+    @Test("Local port 0 is rejected")
+    func zeroLocalPort() {
+        #expect(createConfig(localPort: 0).validate() == [.invalidLocalPort(0)])
+        #expect(createConfig(localPort: 70000).validate() == [.invalidLocalPort(70000)])
+    }
+
+    @Test("Remote port 0 is rejected")
+    func zeroRemotePort() {
+        #expect(createConfig(remotePort: 0).validate() == [.invalidRemotePort(0)])
+    }
+
+    @Test("Proxy port equal to local port is rejected")
+    func proxyPortMatchesLocalPort() {
+        #expect(createConfig(localPort: 8080, proxyPort: 8080).validate() == [.proxyPortMatchesLocalPort(8080)])
+    }
+
+    @Test("Out-of-range proxy port is rejected")
+    func invalidProxyPort() {
+        #expect(createConfig(proxyPort: 0).validate() == [.invalidProxyPort(0)])
+    }
+
+    @Test("kubectl verbosity must be between 0 and 9")
+    func kubectlVerbosityRange() {
+        var config = createConfig()
+        config.kubectlVerbosity = 9
+        #expect(config.validate().isEmpty)
+
+        config.kubectlVerbosity = 10
+        #expect(config.validate() == [.invalidKubectlVerbosity(10)])
+
+        config.kubectlVerbosity = -1
+        #expect(config.validate() == [.invalidKubectlVerbosity(-1)])
+    }
+
+    @Test("Every violated rule is reported")
+    func reportsAllErrors() {
+        let config = createConfig(name: "", service: "", localPort: 0, remotePort: 0)
+        #expect(config.validate() == [.emptyName, .emptyService, .invalidLocalPort(0), .invalidRemotePort(0)])
+    }
+
+    // MARK: - Saving
+
+    @Test("Adding an invalid config throws and saves nothing")
+    @MainActor
+    func addConnectionRejectsInvalidConfig() {
+        let manager = PortForwardManager()
+        let countBefore = manager.connections.count
+
+        #expect(throws: InvalidPortForwardConfigError.self) {
+            try manager.addConnection(createConfig(namespace: ""))
+        }
+        #expect(manager.connections.count == countBefore)
+    }
+
+    @Test("Updating with an invalid config throws and keeps the last valid config")
+    @MainActor
+    func updateConnectionRejectsInvalidConfig() {
+        let manager = PortForwardManager()
+        let original = createConfig()
+        // Appended directly so the test never persists anything.
+        manager.connections = [PortForwardConnectionState(config: original)]
+
+        var edited = original
+        edited.localPort = 70000
+
+        #expect(throws: InvalidPortForwardConfigError.self) {
+            try manager.updateConnection(edited)
+        }
+        #expect(manager.connections[0].config == original)
+    }
+
+    // MARK: - Environment Substitution
+
+    @Test("Set variables are substituted into namespace and service")
+    func resolvesSetVariables() throws {
+        let config = createConfig(namespace: "${TEAM}-dev", service: "${TEAM}-api")
+        let resolved = try config.resolveEnv(["TEAM": "payments"])
+
+        #expect(resolved.namespace == "payments-dev")
+        #expect(resolved.service == "payments-api")
+        #expect(resolved.id == config.id)
+    }
+
+    @Test("An unset variable fails with its name and field")
+    func unsetVariableFails() {
+        let config = createConfig(service: "${MISSING_SERVICE}")
+
+        #expect(throws: PortForwardEnvironmentError.undefinedVariable(name: "MISSING_SERVICE", field: "service")) {
+            try config.resolveEnv([:])
+        }
+    }
+
+    @Test("$$ escapes a literal dollar sign and bare $ is left alone")
+    func literalDollarEscaping() throws {
+        let config = createConfig(namespace: "$${TEAM}", service: "price$5")
+        let resolved = try config.resolveEnv(["TEAM": "payments"])
+
+        #expect(resolved.namespace == "${TEAM}")
+        #expect(resolved.service == "price$5")
+    }
+
+    @Test("An unterminated reference is rejected")
+    func unterminatedReferenceFails() {
+        let config = createConfig(namespace: "${TEAM")
+
+        #expect(throws: PortForwardEnvironmentError.malformedReference(field: "namespace")) {
+            try config.resolveEnv(["TEAM": "payments"])
+        }
+    }
+}
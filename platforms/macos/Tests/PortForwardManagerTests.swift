@@ -0,0 +1,251 @@
+import Testing
+import Foundation
+@testable import PortKiller
+
+/**
+ * Tests for PortForwardManager connection lifecycle.
+ *
+ * Connections are appended directly (not via addConnection) so the tests
+ * never persist anything to user defaults.
+ */
+@MainActor
+struct PortForwardManagerTests {
+
+    // MARK: - Test Fixtures
+
+    func createManager(with config: PortForwardConnectionConfig) -> PortForwardManager {
+        let manager = PortForwardManager()
+        manager.connections = [PortForwardConnectionState(config: config)]
+        return manager
+    }
+
+    func createConfig(name: String = "api", localPort: Int = 18080) -> PortForwardConnectionConfig {
+        PortForwardConnectionConfig(
+            name: name,
+            namespace: "default",
+            service: name,
+            localPort: localPort,
+            remotePort: 80,
+            useDirectExec: false
+        )
+    }
+
+    /// Launches a harmless long-running child to stand in for kubectl/socat.
+    func spawnFakeChild() throws -> Process {
+        let process = Process()
+        process.executableURL = URL(fileURLWithPath: "/bin/sleep")
+        process.arguments = ["30"]
+        try process.run()
+        return process
+    }
+
+    /// Launches a child and waits for it to exit, so it is tracked but no longer running.
+    func spawnExitedChild() throws -> Process {
+        let process = Process()
+        process.executableURL = URL(fileURLWithPath: "/usr/bin/true")
+        try process.run()
+        process.waitUntilExit()
+        return process
+    }
+
+    /// Writes a stand-in kubectl that ignores its arguments and stays up like a live port-forward.
+    func createFakeKubectl() throws -> String {
+        let path = FileManager.default.temporaryDirectory
+            .appendingPathComponent("fake-kubectl-\(UUID().uuidString)").path
+        try PortForwardProcessManager().writeExecutableScript("#!/bin/sh\nexec /bin/sleep 30\n", to: path)
+        return path
+    }
+
+    // MARK: - Cancellation
+
+    @Test("Stopping during start aborts cleanly and leaves the connection disconnected")
+    func stopDuringStartAborts() async {
+        let config = createConfig()
+        let manager = createManager(with: config)
+        let state = manager.connections[0]
+
+        manager.startConnection(config.id)
+        #expect(state.portForwardStatus == .connecting)
+        let startTask = state.portForwardTask
+
+        manager.stopConnection(config.id)
+        await startTask?.value
+
+        #expect(state.portForwardStatus == .disconnected)
+        #expect(state.proxyStatus == .disconnected)
+        #expect(state.lastError == nil)
+        #expect(await !manager.processManager.isProcessRunning(for: config.id, type: .portForward))
+    }
+
+    @Test("Stopping during the stabilization wait kills the spawned kubectl")
+    func stopDuringStabilizationKillsChild() async throws {
+        let kubectl = try createFakeKubectl()
+        defer { try? FileManager.default.removeItem(atPath: kubectl) }
+
+        let config = createConfig()
+        let manager = PortForwardManager(processManager: PortForwardProcessManager(kubectlPath: { kubectl }))
+        manager.connections = [PortForwardConnectionState(config: config)]
+        let state = manager.connections[0]
+
+        manager.startConnection(config.id)
+        let startTask = state.portForwardTask
+
+        // Wait until the child is tracked; the start is now sleeping before checking it.
+        var child: Process?
+        while child == nil {
+            child = await manager.processManager.processes[config.id]?[.portForward]
+            await Task.yield()
+        }
+        let spawned = try #require(child)
+        #expect(spawned.isRunning)
+        #expect(state.portForwardStatus == .connecting)
+
+        manager.stopConnection(config.id)
+        await startTask?.value
+        spawned.waitUntilExit()
+
+        #expect(!spawned.isRunning)
+        #expect(state.portForwardStatus == .disconnected)
+        #expect(state.proxyStatus == .disconnected)
+        #expect(state.lastError == nil)
+        #expect(await !manager.processManager.isProcessRunning(for: config.id, type: .portForward))
+    }
+
+    @Test("A start that begins while an aborted start cleans up keeps its status and process")
+    func restartDuringAbortCleanupSurvives() async throws {
+        let kubectl = try createFakeKubectl()
+        defer { try? FileManager.default.removeItem(atPath: kubectl) }
+
+        let config = createConfig()
+        let manager = PortForwardManager(processManager: PortForwardProcessManager(kubectlPath: { kubectl }))
+        manager.connections = [PortForwardConnectionState(config: config)]
+        let state = manager.connections[0]
+
+        // The cancelled start's leftover child, which the abort must reap.
+        let stale = try spawnFakeChild()
+        await manager.processManager.trackProcess(stale, for: config.id, type: .portForward)
+
+        let abort = Task { await manager.abortCancelledStart(for: state) }
+        // Once the cleanup is pending the abort is suspended on the kill; restart right then.
+        while state.pendingCleanup == nil {
+            await Task.yield()
+        }
+        manager.startConnection(config.id)
+        let restart = state.portForwardTask
+
+        await abort.value
+        stale.waitUntilExit()
+        #expect(state.portForwardStatus == .connecting)
+
+        var child: Process?
+        while child == nil {
+            child = await manager.processManager.processes[config.id]?[.portForward]
+            await Task.yield()
+        }
+        let spawned = try #require(child)
+        #expect(spawned !== stale)
+        #expect(spawned.isRunning)
+
+        manager.stopConnection(config.id)
+        await restart?.value
+        spawned.waitUntilExit()
+    }
+
+    // MARK: - Stop Summary
+
+    @Test("Stop all reports the connections and tracked processes it stopped")
+    func stopAllReportsCounts() async throws {
+        let web = createConfig(name: "web", localPort: 18081)
+        let db = createConfig(name: "db", localPort: 18082)
+        let idle = createConfig(name: "idle", localPort: 18083)
+        let manager = PortForwardManager()
+        manager.connections = [web, db, idle].map { PortForwardConnectionState(config: $0) }
+        manager.connections[0].portForwardStatus = .connected
+        manager.connections[0].proxyStatus = .connected
+        manager.connections[1].portForwardStatus = .connected
+
+        let processManager = manager.processManager
+        let children = try [spawnFakeChild(), spawnFakeChild(), spawnFakeChild()]
+        await processManager.trackProcess(children[0], for: web.id, type: .portForward)
+        await processManager.trackProcess(children[1], for: web.id, type: .proxy)
+        await processManager.trackProcess(children[2], for: db.id, type: .portForward)
+        // Already exited: tracked, but nothing to stop.
+        await processManager.trackProcess(try spawnExitedChild(), for: db.id, type: .proxy)
+
+        let summary = await manager.stopAll()
+
+        #expect(summary == PortForwardStopSummary(connectionsStopped: 2, kubectlKilled: 2, socatKilled: 1))
+        #expect(manager.lastStopSummary == summary)
+        #expect(summary.message == "Stopped 2 connections")
+        for child in children {
+            child.waitUntilExit()
+            #expect(!child.isRunning)
+        }
+    }
+
+    @Test("Stop all counts the process of a start that is still stabilizing")
+    func stopAllCountsStartingConnection() async throws {
+        let kubectl = try createFakeKubectl()
+        defer { try? FileManager.default.removeItem(atPath: kubectl) }
+
+        let config = createConfig()
+        let manager = PortForwardManager(processManager: PortForwardProcessManager(kubectlPath: { kubectl }))
+        manager.connections = [PortForwardConnectionState(config: config)]
+
+        manager.startConnection(config.id)
+        let startTask = manager.connections[0].portForwardTask
+        var child: Process?
+        while child == nil {
+            child = await manager.processManager.processes[config.id]?[.portForward]
+            await Task.yield()
+        }
+
+        let summary = await manager.stopAll()
+        await startTask?.value
+
+        #expect(summary == PortForwardStopSummary(connectionsStopped: 1, kubectlKilled: 1))
+        #expect(summary.message == "Stopped 1 connection")
+        child?.waitUntilExit()
+    }
+
+    @Test("Connections that already failed aren't counted as stopped")
+    func stopAllSkipsFailedConnections() async {
+        let up = createConfig(name: "up", localPort: 18084)
+        let failed = createConfig(name: "failed", localPort: 18085)
+        let manager = PortForwardManager()
+        manager.connections = [up, failed].map { PortForwardConnectionState(config: $0) }
+        manager.connections[0].portForwardStatus = .connecting
+        manager.connections[1].portForwardStatus = .error
+
+        let summary = await manager.stopAll()
+
+        #expect(summary.connectionsStopped == 1)
+    }
+
+    @Test("Starting a connection clears the previous stop summary")
+    func startConnectionClearsStopSummary() async throws {
+        let kubectl = try createFakeKubectl()
+        defer { try? FileManager.default.removeItem(atPath: kubectl) }
+
+        let config = createConfig()
+        let manager = PortForwardManager(processManager: PortForwardProcessManager(kubectlPath: { kubectl }))
+        manager.connections = [PortForwardConnectionState(config: config)]
+        manager.lastStopSummary = PortForwardStopSummary(connectionsStopped: 2)
+
+        manager.startConnection(config.id)
+        #expect(manager.lastStopSummary == nil)
+
+        let startTask = manager.connections[0].portForwardTask
+        manager.stopConnection(config.id)
+        await startTask?.value
+    }
+
+    @Test("Killing a connection with no tracked processes reports nothing")
+    func killUntrackedConnectionReportsZero() async {
+        let processManager = PortForwardProcessManager()
+
+        let summary = await processManager.killProcesses(for: UUID())
+
+        #expect(summary == PortForwardStopSummary())
+    }
+}
@@ -0,0 +1,75 @@
+import Foundation
+import Testing
+@testable import PortKiller
+
+/**
+ * Tests for kubectl/socat invocation built by PortForwardProcessManager.
+ */
+struct PortForwardProcessManagerTests {
+
+    // MARK: - kubectl Arguments
+
+    @Test("Verbosity appends -v=<n> to kubectl port-forward")
+    func verbosityAppendsFlag() {
+        let arguments = PortForwardProcessManager.portForwardArguments(
+            namespace: "default",
+            service: "api",
+            portMapping: "8080:80",
+            verbosity: 6
+        )
+
+        #expect(arguments == ["port-forward", "-n", "default", "svc/api", "8080:80", "--address=127.0.0.1", "-v=6"])
+    }
+
+    @Test("Default verbosity omits the flag")
+    func defaultVerbosityOmitsFlag() {
+        let arguments = PortForwardProcessManager.portForwardArguments(
+            namespace: "default",
+            service: "api",
+            portMapping: "8080:80"
+        )
+
+        #expect(!arguments.contains { $0.hasPrefix("-v=") })
+    }
+
+    @Test("Wrapper script passes verbosity and keeps kubectl stderr for the log")
+    func wrapperScriptPassesVerbosity() {
+        let manager = PortForwardProcessManager()
+
+        let verbose = manager.createWrapperScript(
+            kubectlPath: "/usr/local/bin/kubectl",
+            socatPath: "/usr/local/bin/socat",
+            namespace: "default",
+            service: "api",
+            remotePort: 80,
+            verbosity: 6
+        )
+        let quiet = manager.createWrapperScript(
+            kubectlPath: "/usr/local/bin/kubectl",
+            socatPath: "/usr/local/bin/socat",
+            namespace: "default",
+            service: "api",
+            remotePort: 80
+        )
+
+        #expect(verbose.contains("svc/api $PORT:80 --address=127.0.0.1 -v=6 >/dev/null &"))
+        #expect(quiet.contains("svc/api $PORT:80 --address=127.0.0.1 >/dev/null 2>&1 &"))
+    }
+
+    // MARK: - Wrapper Script
+
+    @Test("Wrapper script is written with the executable bit set")
+    func wrapperScriptIsExecutable() throws {
+        let manager = PortForwardProcessManager()
+        let path = FileManager.default.temporaryDirectory
+            .appendingPathComponent("pf-wrapper-test-\(UUID().uuidString).sh").path
+        defer { try? FileManager.default.removeItem(atPath: path) }
+
+        try manager.writeExecutableScript("#!/bin/bash\nexit 0\n", to: path)
+
+        let attributes = try FileManager.default.attributesOfItem(atPath: path)
+        let permissions = try #require(attributes[.posixPermissions] as? NSNumber).intValue
+        #expect(permissions & 0o111 != 0)
+        #expect(FileManager.default.isExecutableFile(atPath: path))
+    }
+}
@@ -0,0 +1,48 @@
+import Testing
+@testable import PortKiller
+
+struct PortGroupingServiceTests {
+
+    // MARK: - Group By PID
+
+    @Test("Collects every port held by a single PID")
+    func groupsMultiplePortsForOnePid() async {
+        let ports = [
+            createPort(port: 9229, pid: 100, command: "node --inspect server.js"),
+            createPort(port: 3000, pid: 100, command: "node --inspect server.js"),
+            createPort(port: 3001, pid: 100, command: "node --inspect server.js"),
+            createPort(port: 5432, pid: 200, processName: "postgres", command: "postgres -D /data")
+        ]
+
+        let groups = await PortGroupingService.shared.groupByPid(ports)
+
+        #expect(groups.count == 2)
+        let node = groups.first { $0.pid == 100 }
+        #expect(node?.processName == "node")
+        #expect(node?.command == "node --inspect server.js")
+        #expect(node?.ports == [3000, 3001, 9229])
+        #expect(groups.first { $0.pid == 200 }?.ports == [5432])
+    }
+
+    @Test("Keeps same-named processes with different PIDs separate")
+    func separatesSameNameDifferentPid() async {
+        let ports = [
+            createPort(port: 3000, pid: 101),
+            createPort(port: 4000, pid: 100)
+        ]
+
+        let groups = await PortGroupingService.shared.groupByPid(ports)
+
+        #expect(groups.map(\.pid) == [100, 101])
+        #expect(groups.map(\.ports) == [[4000], [3000]])
+    }
+
+    @Test("Skips inactive placeholder ports")
+    func skipsInactivePorts() async {
+        let ports = [createPort(port: 3000, pid: 100), PortInfo.inactive(port: 8080)]
+
+        let groups = await PortGroupingService.shared.groupByPid(ports)
+
+        #expect(groups.map(\.pid) == [100])
+    }
+}
@@ -0,0 +1,21 @@
+import Testing
+@testable import PortKiller
+
+struct PortInfoTests {
+
+    // MARK: - Privileged Ports
+
+    @Test("Port 1023 is privileged")
+    func port1023IsPrivileged() {
+        let port = createPort(port: 1023)
+        #expect(port.isPrivilegedPort)
+        #expect(port.privilegedPortHint != nil)
+    }
+
+    @Test("Port 1024 is not privileged")
+    func port1024IsNotPrivileged() {
+        let port = createPort(port: 1024)
+        #expect(!port.isPrivilegedPort)
+        #expect(port.privilegedPortHint == nil)
+    }
+}
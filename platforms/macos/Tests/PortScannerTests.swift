@@ -0,0 +1,77 @@
+import Testing
+@testable import PortKiller
+
+/**
+ * Tests for PortScanner backend reporting, driven by an injected command runner.
+ */
+struct PortScannerTests {
+
+    // MARK: - Test Fixtures
+
+    let lsofOutput = """
+        COMMAND    PID  USER   FD   TYPE             DEVICE SIZE/OFF NODE NAME
+        node     99999901  code   19u  IPv6 0x3d8015e195af1f3f      0t0  TCP [::1]:3000 (LISTEN)
+        """
+
+    // MARK: - Backend Detection
+
+    @Test("No backend is recorded before the first scan")
+    func noBackendBeforeScan() async {
+        let scanner = PortScanner { _, _ in "" }
+        #expect(await scanner.lastBackend() == nil)
+    }
+
+    @Test("A successful lsof run records the lsof backend")
+    func lsofBackendRecorded() async {
+        let output = lsofOutput
+        let scanner = PortScanner { executable, _ in
+            executable == "/usr/sbin/lsof" ? output : nil
+        }
+
+        let ports = await scanner.scanPorts()
+
+        #expect(ports.map(\.port) == [3000])
+        #expect(await scanner.lastBackend() == .lsof)
+    }
+
+    @Test("lsof running with nothing listening still counts as the lsof backend")
+    func emptyLsofOutputIsLsofBackend() async {
+        let scanner = PortScanner { _, _ in "" }
+
+        #expect(await scanner.scanPorts().isEmpty)
+        #expect(await scanner.lastBackend() == .lsof)
+    }
+
+    @Test("lsof failing to launch is recorded as unavailable")
+    func launchFailureIsUnavailable() async {
+        let scanner = PortScanner { _, _ in nil }
+
+        #expect(await scanner.scanPorts().isEmpty)
+        #expect(await scanner.lastBackend() == .unavailable)
+    }
+
+    @Test("The backend reflects the most recent scan")
+    func backendTracksLatestScan() async {
+        let output = lsofOutput
+        let attempts = Counter()
+        // First launch fails, later ones succeed.
+        let scanner = PortScanner { _, _ in
+            await attempts.increment() == 1 ? nil : output
+        }
+
+        _ = await scanner.scanPorts()
+        #expect(await scanner.lastBackend() == .unavailable)
+
+        _ = await scanner.scanPorts()
+        #expect(await scanner.lastBackend() == .lsof)
+    }
+}
+
+private actor Counter {
+    private var value = 0
+
+    func increment() -> Int {
+        value += 1
+        return value
+    }
+}
@@ -0,0 +1,47 @@
+@testable import PortKiller
+
+/**
+ * Fixtures shared across test suites.
+ */
+
+// MARK: - Ports
+
+/// Creates a sample active port for testing
+func createPort(
+    port: Int = 3000,
+    pid: Int = 12345,
+    processName: String = "node",
+    address: String = "127.0.0.1",
+    user: String = "testuser",
+    command: String = "node server.js"
+) -> PortInfo {
+    PortInfo.active(
+        port: port,
+        pid: pid,
+        processName: processName,
+        address: address,
+        user: user,
+        command: command,
+        fd: "19u"
+    )
+}
+
+// MARK: - AppState
+
+/// Waits until the scanner has started at least `count` scans.
+func waitForScans(_ scanner: MockPortScanner, count: Int) async {
+    while await scanner.scanCount < count {
+        await Task.yield()
+    }
+}
+
+/// Creates an AppState whose initial (auto-refresh) scan has completed.
+@MainActor
+func createAppState(scanner: MockPortScanner, hostnameResolver: HostnameResolver = .shared) async -> AppState {
+    let appState = AppState(scanner: scanner, hostnameResolver: hostnameResolver)
+    appState.stopAutoRefresh()
+    while await scanner.scanCount < 1 || appState.isScanning {
+        await Task.yield()
+    }
+    return appState
+}